@@ -0,0 +1,162 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::parse::ParseError;
+
+/// Known answers for a day's puzzle, read from a file such as
+///
+/// ```toml
+/// part1 = 3749
+/// part2 = "11387"
+/// ```
+///
+/// Only this subset of TOML is understood: one `key = value` pair per line, where the value is
+/// either a bare word or a basic string, and `#` comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    ExpectedKeyValue,
+    UnknownKey(String),
+    UnterminatedString,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExpectedKeyValue => write!(f, "expected `key = value`"),
+            Self::UnknownKey(key) => write!(f, "unknown key `{key}`, expected `part1` or `part2`"),
+            Self::UnterminatedString => write!(f, "unterminated string"),
+        }
+    }
+}
+
+/// Whether an answer matched the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Verdict {
+    /// A short lowercase name for machine-readable output.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail { .. } => "fail",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+impl Answers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, part: u8, answer: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer.trim() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError<ErrorKind>;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        for line in text.lines() {
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(text, content, ErrorKind::ExpectedKeyValue))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted
+                    .split_once('"')
+                    .map(|(string, _)| string)
+                    .ok_or_else(|| ParseError::at(text, value, ErrorKind::UnterminatedString))?,
+                None => value.split('#').next().unwrap_or_default().trim(),
+            };
+
+            let slot = match key {
+                "part1" => &mut answers.part1,
+                "part2" => &mut answers.part2,
+                _ => {
+                    return Err(ParseError::at(
+                        text,
+                        key,
+                        ErrorKind::UnknownKey(key.to_owned()),
+                    ))
+                }
+            };
+            *slot = Some(value.to_owned());
+        }
+        Ok(answers)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Self::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::{Answers, ErrorKind, Verdict},
+        parse::ParseError,
+    };
+
+    #[test]
+    fn test_from_str() {
+        let answers: Answers = "# day 7\npart1 = 3749\npart2 = \"11387\" # concatenation\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.part1.as_deref(), Some("3749"));
+        assert_eq!(answers.part2.as_deref(), Some("11387"));
+
+        assert_eq!(
+            "part1 = 1\npart3 = 2".parse::<Answers>(),
+            Err(ParseError::new(2, 1, ErrorKind::UnknownKey("part3".into())))
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = "part1 = 143".parse().unwrap();
+        assert_eq!(answers.check(1, "143"), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, "142"),
+            Verdict::Fail {
+                expected: "143".into()
+            }
+        );
+        assert_eq!(answers.check(2, "123"), Verdict::Missing);
+    }
+}
@@ -1,4 +1,19 @@
-use std::iter;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+    iter, mem,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    math::{Pos, Vect},
+    parse::ParseError,
+};
+
+pub use bit_grid::BitGrid;
+
+mod bit_grid;
 
 #[derive(Debug, Clone)]
 pub struct Array2<T> {
@@ -17,9 +32,44 @@ impl<T> Default for Array2<T> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnevenRows;
 
+impl Display for UnevenRows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rows had different lengths")
+    }
+}
+
+impl Error for UnevenRows {}
+
+/// Offsets to the 4 cells sharing an edge with a cell, clockwise from the one above.
+pub const NEIGHBORS4: [Vect; 4] = [
+    Vect { x: 0, y: -1 },
+    Vect { x: 1, y: 0 },
+    Vect { x: 0, y: 1 },
+    Vect { x: -1, y: 0 },
+];
+
+/// Offsets to the 8 cells sharing an edge or a corner with a cell, clockwise from the one
+/// above.
+pub const NEIGHBORS8: [Vect; 8] = [
+    Vect { x: 0, y: -1 },
+    Vect { x: 1, y: -1 },
+    Vect { x: 1, y: 0 },
+    Vect { x: 1, y: 1 },
+    Vect { x: 0, y: 1 },
+    Vect { x: -1, y: 1 },
+    Vect { x: -1, y: 0 },
+    Vect { x: -1, y: -1 },
+];
+
+/// Neighbors borrowed mutably by [`Array2::neighbors_mut`], along with their positions.
+pub type NeighborsMut<'a, T> = Vec<(Pos, &'a mut T)>;
+
+/// The positions of each marker character found by [`Array2::parse_grid_with_markers`].
+pub type Markers = HashMap<char, Vec<Pos>>;
+
 impl<T> Array2<T> {
     pub fn from_rows<Row>(rows: impl IntoIterator<Item = Row>) -> Result<Self, UnevenRows>
     where
@@ -27,11 +77,7 @@ impl<T> Array2<T> {
     {
         let mut rows = rows.into_iter();
         let Some(first_row) = rows.next() else {
-            return Ok(Self {
-                data: vec![],
-                width: 0,
-                height: 0,
-            });
+            return Ok(Self::default());
         };
         let mut data: Vec<_> = first_row.into_iter().collect();
         let width = data.len();
@@ -45,22 +91,28 @@ impl<T> Array2<T> {
             }
         }
 
-        Ok(Self {
+        Ok(Self::with_size(data, width, height))
+    }
+
+    /// Builds an array from its cells in reading order. An array without columns or without
+    /// rows has neither, so that only an empty array has a zero dimension.
+    fn with_size(data: Vec<T>, width: usize, height: usize) -> Self {
+        debug_assert_eq!(data.len(), width * height);
+        if data.is_empty() {
+            return Self::default();
+        }
+        Self {
             data,
             width,
             height,
-        })
+        }
     }
 
     pub fn from_element(elt: T, width: usize, height: usize) -> Self
     where
         T: Clone,
     {
-        Self {
-            data: vec![elt; width * height],
-            width,
-            height,
-        }
+        Self::with_size(vec![elt; width * height], width, height)
     }
 
     pub fn from_default(width: usize, height: usize) -> Self
@@ -70,14 +122,10 @@ impl<T> Array2<T> {
         let data: Vec<_> = iter::repeat_with(Default::default)
             .take(width * height)
             .collect();
-        Self {
-            data,
-            width,
-            height,
-        }
+        Self::with_size(data, width, height)
     }
 
-    pub fn from_fn(width: usize, height: usize, mut func: impl FnMut(isize, isize) -> T) -> Self {
+    pub fn from_fn(width: usize, height: usize, mut func: impl FnMut(Pos) -> T) -> Self {
         if width == 0 || height == 0 {
             return Default::default();
         }
@@ -85,8 +133,8 @@ impl<T> Array2<T> {
         let iwidth = isize::try_from(width).expect("`Array2` was too big");
         let iheight = isize::try_from(height).expect("`Array2` was too big");
         let data = (0..iheight)
-            .flat_map(|y| (0..iwidth).map(move |x| (x, y)))
-            .map(|(x, y)| func(x, y))
+            .flat_map(|y| (0..iwidth).map(move |x| Pos::new(x, y)))
+            .map(&mut func)
             .collect();
 
         Self {
@@ -96,6 +144,53 @@ impl<T> Array2<T> {
         }
     }
 
+    /// Parses a grid with one cell per character, converted by `cell`. All lines must have the
+    /// same number of characters, otherwise the first line that doesn't is reported as
+    /// [`UnevenRows`].
+    pub fn parse_grid<E: From<UnevenRows>>(
+        text: &str,
+        cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError<E>> {
+        Self::parse_grid_with_markers(text, |_| false, cell).map(|(grid, _)| grid)
+    }
+
+    /// Like [`Array2::parse_grid`], but also collects the positions of the characters for which
+    /// `is_marker` holds, such as a starting point or antennas. The positions of each marker
+    /// are in reading order.
+    pub fn parse_grid_with_markers<E: From<UnevenRows>>(
+        text: &str,
+        is_marker: impl Fn(char) -> bool,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<(Self, Markers), ParseError<E>> {
+        let mut data = vec![];
+        let mut width = None;
+        let mut height = 0;
+        let mut markers = Markers::new();
+
+        for (y, line) in text.lines().enumerate() {
+            let row_start = data.len();
+            for (x, (offset, c)) in line.char_indices().enumerate() {
+                if is_marker(c) {
+                    let pos = Pos::new(x as isize, y as isize);
+                    markers.entry(c).or_default().push(pos);
+                }
+                data.push(cell(c).map_err(|err| ParseError::at(text, &line[offset..], err))?);
+            }
+
+            let row_width = data.len() - row_start;
+            if width
+                .replace(row_width)
+                .is_some_and(|width| width != row_width)
+            {
+                return Err(ParseError::at(text, line, UnevenRows.into()));
+            }
+            height += 1;
+        }
+
+        let grid = Self::with_size(data, width.unwrap_or(0), height);
+        Ok((grid, markers))
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -108,21 +203,134 @@ impl<T> Array2<T> {
         (self.width, self.height)
     }
 
-    fn pos_to_index(&self, x: isize, y: isize) -> Option<usize> {
-        let x: usize = x.try_into().ok().filter(|&x| x < self.width)?;
-        let y: usize = y.try_into().ok()?;
+    /// Whether `pos` designates one of the cells of the array.
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.within(self.size())
+    }
+
+    fn pos_to_index(&self, pos: Pos) -> Option<usize> {
+        let x: usize = pos.x.try_into().ok().filter(|&x| x < self.width)?;
+        let y: usize = pos.y.try_into().ok().filter(|&y| y < self.height)?;
         Some(x + y * self.width)
     }
 
-    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
-        self.data.get(self.pos_to_index(x, y)?)
+    fn wrapping_index(&self, pos: Pos) -> usize {
+        assert!(
+            !self.data.is_empty(),
+            "cannot wrap around an empty `Array2`"
+        );
+        let x = pos.x.rem_euclid(self.width as isize) as usize;
+        let y = pos.y.rem_euclid(self.height as isize) as usize;
+        x + y * self.width
+    }
+
+    fn clamped_index(&self, pos: Pos) -> usize {
+        assert!(!self.data.is_empty(), "cannot clamp to an empty `Array2`");
+        let x = pos.x.clamp(0, self.width as isize - 1) as usize;
+        let y = pos.y.clamp(0, self.height as isize - 1) as usize;
+        x + y * self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.data.get(self.pos_to_index(pos)?)
     }
 
-    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
-        let idx = self.pos_to_index(x, y)?;
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let idx = self.pos_to_index(pos)?;
         self.data.get_mut(idx)
     }
 
+    /// Gets the cell at `pos` as if the array were repeated in every direction, as on a torus.
+    /// Panics if the array is empty.
+    pub fn get_wrapping(&self, pos: Pos) -> &T {
+        &self.data[self.wrapping_index(pos)]
+    }
+
+    pub fn get_wrapping_mut(&mut self, pos: Pos) -> &mut T {
+        let idx = self.wrapping_index(pos);
+        &mut self.data[idx]
+    }
+
+    /// Gets the cell closest to `pos`, so that positions past an edge get the cell on the edge.
+    /// Panics if the array is empty.
+    pub fn get_clamped(&self, pos: Pos) -> &T {
+        &self.data[self.clamped_index(pos)]
+    }
+
+    pub fn get_clamped_mut(&mut self, pos: Pos) -> &mut T {
+        let idx = self.clamped_index(pos);
+        &mut self.data[idx]
+    }
+
+    fn out_of_bounds(&self, pos: Pos) -> ! {
+        panic!(
+            "position ({}, {}) is out of bounds for a {}x{} `Array2`",
+            pos.x, pos.y, self.width, self.height
+        )
+    }
+
+    /// Iterates over the cells at the offsets in `stencil` from `pos` that are in bounds, along
+    /// with their positions.
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: Pos,
+        stencil: &'a [Vect],
+    ) -> impl Iterator<Item = (Pos, &'a T)> + 'a {
+        stencil.iter().filter_map(move |&offset| {
+            let neighbor = pos + offset;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
+    /// The cells sharing an edge with `pos`, see [`Array2::neighbors`].
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbors(pos, &NEIGHBORS4)
+    }
+
+    /// The cells sharing an edge or a corner with `pos`, see [`Array2::neighbors`].
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbors(pos, &NEIGHBORS8)
+    }
+
+    /// Borrows the cell at `pos` and its in-bounds neighbors at the offsets in `stencil`
+    /// mutably at the same time. The neighbors are returned in reading order, and offsets
+    /// leading back to the cell itself or to an already borrowed neighbor are skipped. Returns
+    /// `None` if `pos` is out of bounds.
+    pub fn neighbors_mut(
+        &mut self,
+        pos: Pos,
+        stencil: &[Vect],
+    ) -> Option<(&mut T, NeighborsMut<'_, T>)> {
+        let center = self.pos_to_index(pos)?;
+        let mut targets: Vec<(usize, Pos)> = stencil
+            .iter()
+            .filter_map(|&offset| {
+                let neighbor = pos + offset;
+                Some((self.pos_to_index(neighbor)?, neighbor))
+            })
+            .collect();
+        targets.push((center, pos));
+        targets.sort_unstable_by_key(|&(idx, _)| idx);
+        targets.dedup_by_key(|&mut (idx, _)| idx);
+
+        let mut rest = self.data.as_mut_slice();
+        let mut rest_start = 0;
+        let mut center_cell = None;
+        let mut neighbors = Vec::with_capacity(targets.len() - 1);
+        for (idx, neighbor) in targets {
+            let (_, tail) = mem::take(&mut rest).split_at_mut(idx - rest_start);
+            let (cell, tail) = tail.split_first_mut().expect("index was in bounds");
+            rest = tail;
+            rest_start = idx + 1;
+            if idx == center {
+                center_cell = Some(cell);
+            } else {
+                neighbors.push((neighbor, cell));
+            }
+        }
+        Some((center_cell.expect("center was borrowed"), neighbors))
+    }
+
     pub fn raw_data(&self) -> &[T] {
         &self.data
     }
@@ -138,42 +346,346 @@ impl<T> Array2<T> {
     fn index_iter<U>(
         iter: impl Iterator<Item = U>,
         width: usize,
-    ) -> impl Iterator<Item = (isize, isize, U)> {
-        let mut x = 0;
-        let mut y = 0;
+    ) -> impl Iterator<Item = (Pos, U)> {
+        let mut pos = Pos::new(0, 0);
         let width: isize = width
             .try_into()
             .expect("`Array2` width didn't fit in an `isize`");
         iter.map(move |elt| {
-            let eltx = x;
-            let elty = y;
-            x += 1;
-            if x >= width {
-                x = 0;
-                y += 1;
+            let elt_pos = pos;
+            pos.x += 1;
+            if pos.x >= width {
+                pos.x = 0;
+                pos.y += 1;
             }
-            (eltx, elty, elt)
+            (elt_pos, elt)
         })
     }
 
-    pub fn indexed_iter(&self) -> impl Iterator<Item = (isize, isize, &T)> {
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
         Self::index_iter(self.data.iter(), self.width)
     }
 
-    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (isize, isize, &mut T)> {
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
         Self::index_iter(self.data.iter_mut(), self.width)
     }
 
-    pub fn into_indexed_iter(self) -> impl Iterator<Item = (isize, isize, T)> {
+    pub fn into_indexed_iter(self) -> impl Iterator<Item = (Pos, T)> {
         Self::index_iter(self.data.into_iter(), self.width)
     }
 
     pub fn rows(&self) -> impl Iterator<Item = &[T]> {
-        self.data.chunks(self.width)
+        self.data.chunks(self.width.max(1))
     }
 
     pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
-        self.data.chunks_mut(self.width)
+        self.data.chunks_mut(self.width.max(1))
+    }
+
+    /// Iterates over the cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width || self.data.is_empty(),
+            "column {x} is out of bounds for a {}x{} `Array2`",
+            self.width,
+            self.height
+        );
+        self.data.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over the diagonals going down and to the right, each from its top-left end.
+    /// The first diagonal is the one starting in the bottom-left corner and the last one starts
+    /// in the top-right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.diagonal_starts(true)
+            .map(|start| self.ray(start, Vect::new(1, 1)))
+    }
+
+    /// Iterates over the diagonals going down and to the left, each from its top-right end.
+    /// The first diagonal is the one starting in the top-left corner and the last one starts
+    /// in the bottom-right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.diagonal_starts(false)
+            .map(|start| self.ray(start, Vect::new(-1, 1)))
+    }
+
+    fn diagonal_starts(&self, down_right: bool) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let left_column = (1..height).rev().map(|y| Pos::new(0, y));
+        let top_row = (0..width).map(|x| Pos::new(x, 0));
+        let right_column = (1..height).map(move |y| Pos::new(width - 1, y));
+        let (before, after) = if down_right {
+            (Some(left_column), None)
+        } else {
+            (None, Some(right_column))
+        };
+        before
+            .into_iter()
+            .flatten()
+            .chain(top_row)
+            .chain(after.into_iter().flatten())
+    }
+
+    fn ray(&self, start: Pos, step: Vect) -> impl Iterator<Item = &T> {
+        (0..).map_while(move |t| self.get(start + t * step))
+    }
+
+    /// Renders the array as text with the character given by `cell` for each cell, one line
+    /// per row. Without rulers the output can be read back with [`Array2::parse_grid`].
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> Render<'_, T, F> {
+        Render {
+            array: self,
+            cell,
+            rulers: false,
+            highlights: HashMap::new(),
+        }
+    }
+
+    /// Borrows the `width` by `height` rectangle whose top-left corner is at `origin`, or
+    /// returns `None` if it doesn't fit in the array.
+    pub fn view(&self, origin: Pos, width: usize, height: usize) -> Option<ArrayView2<'_, T>> {
+        let fits = |start: isize, len: usize, max: usize| {
+            usize::try_from(start)
+                .is_ok_and(|start| start.checked_add(len).is_some_and(|end| end <= max))
+        };
+        (fits(origin.x, width, self.width) && fits(origin.y, height, self.height)).then_some(
+            ArrayView2 {
+                array: self,
+                origin,
+                width,
+                height,
+            },
+        )
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |pos| Pos::new(pos.y, pos.x))
+    }
+
+    /// Rotates a quarter turn clockwise, so that the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.height as isize - 1;
+        self.remap(self.height, self.width, |pos| {
+            Pos::new(pos.y, last_row - pos.x)
+        })
+    }
+
+    /// Rotates a quarter turn counterclockwise, so that the top row becomes the left column.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_column = self.width as isize - 1;
+        self.remap(self.height, self.width, |pos| {
+            Pos::new(last_column - pos.y, pos.x)
+        })
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_column = self.width as isize - 1;
+        self.remap(self.width, self.height, |pos| {
+            Pos::new(last_column - pos.x, pos.y)
+        })
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.height as isize - 1;
+        self.remap(self.width, self.height, |pos| {
+            Pos::new(pos.x, last_row - pos.y)
+        })
+    }
+
+    /// Builds a `width` by `height` array whose cell at each position is cloned from the
+    /// position given by `source`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(width, height, |pos| self[source(pos)].clone())
+    }
+}
+
+/// A borrowed rectangle of an [`Array2`], see [`Array2::view`]. Positions are relative to its
+/// top-left corner.
+#[derive(Debug)]
+pub struct ArrayView2<'a, T> {
+    array: &'a Array2<T>,
+    origin: Pos,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for ArrayView2<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArrayView2<'_, T> {}
+
+impl<'a, T> ArrayView2<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.within(self.size())
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&'a T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        self.array.get(self.origin + (pos - Pos::ORIGIN))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let Self {
+            array,
+            origin,
+            width,
+            height,
+        } = *self;
+        let start = origin.x as usize;
+        (0..height).map(move |y| {
+            let row_start = start + (origin.y as usize + y) * array.width;
+            &array.data[row_start..row_start + width]
+        })
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &'a T)> + 'a {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, cell)| (Pos::new(x as isize, y as isize), cell))
+        })
+    }
+
+    /// Copies the viewed cells into a new array.
+    pub fn to_array(&self) -> Array2<T>
+    where
+        T: Clone,
+    {
+        Array2::from_rows(self.rows().map(|row| row.iter().cloned()))
+            .expect("rows of a view have the same length")
+    }
+}
+
+impl<T> Index<Pos> for ArrayView2<'_, T> {
+    type Output = T;
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position ({}, {}) is out of bounds for a {}x{} `ArrayView2`",
+                pos.x, pos.y, self.width, self.height
+            )
+        })
+    }
+}
+
+/// An [`Array2`] rendered as text, see [`Array2::render`].
+pub struct Render<'a, T, F> {
+    array: &'a Array2<T>,
+    cell: F,
+    rulers: bool,
+    highlights: HashMap<Pos, char>,
+}
+
+impl<T, F: Fn(&T) -> char> Render<'_, T, F> {
+    /// Adds the column numbers above the grid and the row numbers to its left. The tens of the
+    /// column numbers are on their own line, shown only on multiples of ten.
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    /// Draws `c` in place of the cells at `positions`. Positions outside the array are ignored.
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = Pos>, c: char) -> Self {
+        self.highlights
+            .extend(positions.into_iter().map(|pos| (pos, c)));
+        self
+    }
+}
+
+impl<T, F: Fn(&T) -> char> Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label_width = self.array.height.saturating_sub(1).to_string().len();
+        if self.rulers {
+            let margin = " ".repeat(label_width + 1);
+            if self.array.width > 10 {
+                let tens: String = (0..self.array.width)
+                    .map(|x| match x % 10 {
+                        0 => char::from_digit((x / 10 % 10) as u32, 10).unwrap(),
+                        _ => ' ',
+                    })
+                    .collect();
+                writeln!(f, "{margin}{}", tens.trim_end())?;
+            }
+            let ones: String = (0..self.array.width)
+                .map(|x| char::from_digit((x % 10) as u32, 10).unwrap())
+                .collect();
+            writeln!(f, "{margin}{ones}")?;
+        }
+
+        for (y, row) in self.array.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            if self.rulers {
+                write!(f, "{y:>label_width$} ")?;
+            }
+            for (x, cell) in row.iter().enumerate() {
+                let pos = Pos::new(x as isize, y as isize);
+                let c = match self.highlights.get(&pos) {
+                    Some(&c) => c,
+                    None => (self.cell)(cell),
+                };
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the cells of each row next to each other, one row per line.
+impl<T: Display> Display for Array2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -186,8 +698,191 @@ where
     }
 }
 
+impl<T> Index<Pos> for Array2<T> {
+    type Output = T;
+    fn index(&self, pos: Pos) -> &T {
+        match self.pos_to_index(pos) {
+            Some(idx) => &self.data[idx],
+            None => self.out_of_bounds(pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Array2<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.pos_to_index(pos) {
+            Some(idx) => &mut self.data[idx],
+            None => self.out_of_bounds(pos),
+        }
+    }
+}
+
 impl<T> AsRef<Array2<T>> for Array2<T> {
     fn as_ref(&self) -> &Array2<T> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array2::{Array2, UnevenRows, NEIGHBORS4, NEIGHBORS8},
+        math::{Pos, Vect},
+        parse::ParseError,
+    };
+
+    #[test]
+    fn test_parse_grid() {
+        let (grid, markers) = Array2::parse_grid_with_markers(
+            "#.S\n.#E\n",
+            |c| c.is_ascii_uppercase(),
+            |c| Ok::<_, UnevenRows>(c == '#'),
+        )
+        .unwrap();
+        assert_eq!(grid.size(), (3, 2));
+        assert_eq!(grid.raw_data(), &[true, false, false, false, true, false]);
+        assert_eq!(markers[&'S'], &[Pos::new(2, 0)]);
+        assert_eq!(markers[&'E'], &[Pos::new(2, 1)]);
+
+        let err = Array2::parse_grid("..\n...\n", |_| Ok::<_, UnevenRows>(()));
+        assert_eq!(err.err(), Some(ParseError::new(2, 1, UnevenRows)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Array2::from_fn(3, 3, |pos| pos.x + 3 * pos.y);
+        let values = |iter: &mut dyn Iterator<Item = (Pos, &isize)>| -> Vec<isize> {
+            iter.map(|(_, &value)| value).collect()
+        };
+        assert_eq!(values(&mut grid.neighbors4(Pos::new(0, 0))), [1, 3]);
+        assert_eq!(values(&mut grid.neighbors4(Pos::new(1, 1))), [1, 5, 7, 3]);
+        assert_eq!(values(&mut grid.neighbors8(Pos::new(2, 0))), [5, 4, 1]);
+        let knight = [Vect::new(1, 2), Vect::new(-1, 2), Vect::new(2, 1)];
+        assert_eq!(values(&mut grid.neighbors(Pos::new(0, 0), &knight)), [7, 5]);
+    }
+
+    #[test]
+    fn test_neighbors_mut() {
+        let mut grid = Array2::from_element(1, 3, 3);
+        let (center, neighbors) = grid.neighbors_mut(Pos::new(1, 0), &NEIGHBORS8).unwrap();
+        let positions: Vec<_> = neighbors.iter().map(|(pos, _)| (pos.x, pos.y)).collect();
+        assert_eq!(positions, [(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        for (_, cell) in neighbors {
+            *center += *cell;
+            *cell = 0;
+        }
+        assert_eq!(grid.raw_data(), &[0, 6, 0, 0, 0, 0, 1, 1, 1]);
+        assert!(grid.neighbors_mut(Pos::new(3, 0), &NEIGHBORS4).is_none());
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = Array2::from_fn(3, 2, |pos| (pos.x, pos.y));
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&(2, 1)));
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 2)), None);
+        assert_eq!(grid.get(Pos::new(-1, 0)), None);
+        assert!(grid.contains(Pos::new(2, 1)));
+        assert!(!grid.contains(Pos::new(1, 2)));
+
+        assert_eq!(grid[Pos::new(1, 1)], (1, 1));
+        assert_eq!(*grid.get_wrapping(Pos::new(-1, 5)), (2, 1));
+        assert_eq!(*grid.get_clamped(Pos::new(7, -3)), (2, 0));
+
+        let positions: Vec<Pos> = grid.indexed_iter().map(|(pos, _)| pos).collect();
+        assert_eq!(positions[4], Pos::new(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "position (0, 2) is out of bounds for a 3x2 `Array2`")]
+    fn test_index_out_of_bounds() {
+        let grid = Array2::from_element(0, 3, 2);
+        let _ = grid[Pos::new(0, 2)];
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid: Array2<u8> = Array2::from_rows([[], []]).unwrap();
+        assert_eq!(grid.size(), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(Array2::from_element(0, 0, 4).size(), (0, 0));
+        assert_eq!(Array2::from_rows([vec![1], vec![]]).err(), Some(UnevenRows));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid: Array2<char> = ["abc".chars(), "def".chars()].into_iter().collect();
+        let rows = |grid: &Array2<char>| -> Vec<String> {
+            grid.rows().map(|row| row.iter().collect()).collect()
+        };
+        assert_eq!(rows(&grid.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(rows(&grid.rotate_cw()), ["da", "eb", "fc"]);
+        assert_eq!(rows(&grid.rotate_ccw()), ["cf", "be", "ad"]);
+        assert_eq!(rows(&grid.flip_horizontal()), ["cba", "fed"]);
+        assert_eq!(rows(&grid.flip_vertical()), ["def", "abc"]);
+        assert_eq!(rows(&grid.rotate_cw().rotate_ccw()), rows(&grid));
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid: Array2<char> = ["abc".chars(), "def".chars()].into_iter().collect();
+        let lines = |lines: &mut dyn Iterator<Item = String>| -> Vec<String> { lines.collect() };
+        assert_eq!(
+            lines(&mut grid.columns().map(|column| column.collect())),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            lines(&mut grid.diagonals().map(|diagonal| diagonal.collect())),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            lines(&mut grid.anti_diagonals().map(|diagonal| diagonal.collect())),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = Array2::from_fn(4, 3, |pos| pos.x + 10 * pos.y);
+        let view = grid.view(Pos::new(1, 1), 2, 2).unwrap();
+        assert_eq!(view.get(Pos::new(1, 0)), Some(&12));
+        assert_eq!(view.get(Pos::new(2, 0)), None);
+        assert_eq!(view[Pos::new(0, 1)], 21);
+        assert_eq!(view.rows().collect::<Vec<_>>(), [[11, 12], [21, 22]]);
+        assert_eq!(view.indexed_iter().last(), Some((Pos::new(1, 1), &22)));
+        assert_eq!(view.to_array().raw_data(), &[11, 12, 21, 22]);
+        assert!(grid.view(Pos::new(3, 0), 2, 1).is_none());
+    }
+
+    #[test]
+    fn test_render() {
+        let text = "#..\n.#.\n..#\n#.#";
+        let grid = Array2::parse_grid(text, |c| Ok::<_, UnevenRows>(c == '#')).unwrap();
+        let render = || grid.render(|&wall| if wall { '#' } else { '.' });
+        assert_eq!(render().to_string(), text);
+        assert_eq!(
+            render()
+                .highlight([Pos::new(1, 0), Pos::new(5, 5)], 'O')
+                .to_string(),
+            "#O.\n.#.\n..#\n#.#"
+        );
+        assert_eq!(
+            render().rulers().to_string(),
+            "  012\n0 #..\n1 .#.\n2 ..#\n3 #.#"
+        );
+
+        let wide = Array2::from_element('.', 12, 1);
+        assert_eq!(
+            wide.render(|&c| c).rulers().to_string(),
+            "  0         1\n  012345678901\n0 ............"
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let text = "ab\ncd\nef";
+        let grid = Array2::parse_grid(text, Ok::<_, UnevenRows>).unwrap();
+        assert_eq!(grid.to_string(), text);
+        assert_eq!(Array2::<char>::default().to_string(), "");
+    }
+}
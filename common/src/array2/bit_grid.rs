@@ -0,0 +1,271 @@
+use crate::{array2::Array2, math::Pos};
+
+/// A grid of small bit sets packed into words, such as visited flags or one flag per direction.
+/// Each cell has the same number of bits, a power of two up to 64.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    bits: u32,
+}
+
+impl Clone for BitGrid {
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(),
+            ..*self
+        }
+    }
+
+    /// Reuses the allocation of `self`, so a scratch grid can be reset from a template without
+    /// allocating.
+    fn clone_from(&mut self, source: &Self) {
+        self.words.clone_from(&source.words);
+        self.width = source.width;
+        self.height = source.height;
+        self.bits = source.bits;
+    }
+}
+
+impl BitGrid {
+    /// Creates a grid with one bit per cell, all unset.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_bits(width, height, 1)
+    }
+
+    /// Creates a grid with `bits` bits per cell, all unset.
+    pub fn with_bits(width: usize, height: usize, bits: u32) -> Self {
+        assert!(
+            bits.is_power_of_two() && bits <= u64::BITS,
+            "a `BitGrid` can't have {bits} bits per cell"
+        );
+        let n_bits = width * height * bits as usize;
+        Self {
+            words: vec![0; n_bits.div_ceil(u64::BITS as usize)],
+            width,
+            height,
+            bits,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn bits_per_cell(&self) -> u32 {
+        self.bits
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.within(self.size())
+    }
+
+    /// The bits of the cell at `pos`, `None` if it's out of bounds.
+    pub fn cell(&self, pos: Pos) -> Option<u64> {
+        let (word, shift) = self.locate(pos)?;
+        Some(self.words[word] >> shift & self.cell_mask())
+    }
+
+    /// Whether any bit of the cell at `pos` is set, `false` if it's out of bounds.
+    pub fn get(&self, pos: Pos) -> bool {
+        self.cell(pos).is_some_and(|bits| bits != 0)
+    }
+
+    /// Sets `flags` in the cell at `pos`, returning whether any of them was unset before.
+    pub fn insert(&mut self, pos: Pos, flags: u64) -> bool {
+        let (word, shift) = self.locate_or_panic(pos);
+        let flags = (flags & self.cell_mask()) << shift;
+        let was_missing = self.words[word] & flags != flags;
+        self.words[word] |= flags;
+        was_missing
+    }
+
+    /// Unsets `flags` in the cell at `pos`.
+    pub fn remove(&mut self, pos: Pos, flags: u64) {
+        let (word, shift) = self.locate_or_panic(pos);
+        self.words[word] &= !((flags & self.cell_mask()) << shift);
+    }
+
+    /// Replaces all bits of the cell at `pos`.
+    pub fn set_cell(&mut self, pos: Pos, bits: u64) {
+        let (word, shift) = self.locate_or_panic(pos);
+        self.words[word] &= !(self.cell_mask() << shift);
+        self.words[word] |= (bits & self.cell_mask()) << shift;
+    }
+
+    /// Unsets every bit.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The number of set bits across all cells.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The number of cells with at least one bit set.
+    pub fn count_cells(&self) -> usize {
+        let first_bits = self.first_bit_mask();
+        self.words
+            .iter()
+            .map(|&word| (self.fold_cells(word) & first_bits).count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterates over the positions of the cells with at least one bit set, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| Pos::new(x as isize, y as isize)))
+            .filter(|&pos| self.get(pos))
+    }
+
+    /// Sets every bit that is set in `other`, which must have the same size and bits per cell.
+    pub fn union_with(&mut self, other: &Self) {
+        self.assert_same_shape(other);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Unsets every bit that is unset in `other`, which must have the same size and bits per
+    /// cell.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.assert_same_shape(other);
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+
+    fn cell_mask(&self) -> u64 {
+        u64::MAX >> (u64::BITS - self.bits)
+    }
+
+    /// A word with the lowest bit of every cell set.
+    fn first_bit_mask(&self) -> u64 {
+        u64::MAX / self.cell_mask()
+    }
+
+    /// ORs the bits of each cell of `word` into the lowest bit of the cell.
+    fn fold_cells(&self, mut word: u64) -> u64 {
+        let mut shift = 1;
+        while shift < self.bits {
+            word |= word >> shift;
+            shift *= 2;
+        }
+        word
+    }
+
+    fn locate(&self, pos: Pos) -> Option<(usize, u32)> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let bit = (pos.y as usize * self.width + pos.x as usize) * self.bits as usize;
+        let word_bits = u64::BITS as usize;
+        Some((bit / word_bits, (bit % word_bits) as u32))
+    }
+
+    fn locate_or_panic(&self, pos: Pos) -> (usize, u32) {
+        self.locate(pos).unwrap_or_else(|| {
+            panic!(
+                "position ({}, {}) is out of bounds for a {}x{} `BitGrid`",
+                pos.x, pos.y, self.width, self.height
+            )
+        })
+    }
+
+    fn assert_same_shape(&self, other: &Self) {
+        assert!(
+            self.size() == other.size() && self.bits == other.bits,
+            "`BitGrid`s have different shapes"
+        );
+    }
+}
+
+/// Sets the single bit of each cell that is `true`.
+impl From<&Array2<bool>> for BitGrid {
+    fn from(array: &Array2<bool>) -> Self {
+        let mut grid = Self::new(array.width(), array.height());
+        for (pos, &set) in array.indexed_iter() {
+            if set {
+                grid.insert(pos, 1);
+            }
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array2::{Array2, BitGrid, UnevenRows},
+        math::Pos,
+    };
+
+    #[test]
+    fn test_bit_grid() {
+        let mut grid = BitGrid::new(10, 10);
+        assert!(grid.insert(Pos::new(3, 4), 1));
+        assert!(!grid.insert(Pos::new(3, 4), 1));
+        assert!(grid.insert(Pos::new(9, 9), 1));
+        assert!(grid.get(Pos::new(3, 4)));
+        assert!(!grid.get(Pos::new(4, 3)));
+        assert!(!grid.get(Pos::new(-1, 0)));
+        assert_eq!(grid.count_cells(), 2);
+        assert_eq!(
+            grid.positions().collect::<Vec<_>>(),
+            [Pos::new(3, 4), Pos::new(9, 9)]
+        );
+
+        let mut scratch = BitGrid::new(10, 10);
+        scratch.clone_from(&grid);
+        scratch.remove(Pos::new(3, 4), 1);
+        assert_eq!(scratch.count_ones(), 1);
+        grid.clear();
+        assert_eq!(grid.count_ones(), 0);
+    }
+
+    #[test]
+    fn test_flags() {
+        let mut grid = BitGrid::with_bits(5, 5, 4);
+        assert!(grid.insert(Pos::new(1, 1), 0b0101));
+        assert!(!grid.insert(Pos::new(1, 1), 0b0001));
+        assert!(grid.insert(Pos::new(1, 1), 0b0011));
+        grid.insert(Pos::new(4, 4), 0b1000);
+        assert_eq!(grid.cell(Pos::new(1, 1)), Some(0b0111));
+        assert_eq!(grid.count_ones(), 4);
+        assert_eq!(grid.count_cells(), 2);
+
+        let mut other = BitGrid::with_bits(5, 5, 4);
+        other.set_cell(Pos::new(1, 1), 0b1100);
+        other.set_cell(Pos::new(0, 0), 0b0001);
+        let mut intersection = grid.clone();
+        intersection.intersect_with(&other);
+        assert_eq!(intersection.cell(Pos::new(1, 1)), Some(0b0100));
+        assert_eq!(intersection.count_cells(), 1);
+        grid.union_with(&other);
+        assert_eq!(grid.cell(Pos::new(1, 1)), Some(0b1111));
+        assert_eq!(grid.count_cells(), 3);
+    }
+
+    #[test]
+    fn test_from_array() {
+        let array = Array2::parse_grid("#.\n.#", |c| Ok::<_, UnevenRows>(c == '#')).unwrap();
+        let grid = BitGrid::from(&array);
+        assert_eq!(
+            grid.positions().collect::<Vec<_>>(),
+            [Pos::new(0, 0), Pos::new(1, 1)]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::{Answers, Verdict},
+    solution::Solution,
+};
+
+/// Declares tests that check a [`Solution`] against the examples from the puzzle text.
+///
+/// Each inline example becomes a module with a test per listed part, and `dir` adds a test
+/// running every example found in a directory of the crate, see [`check_dir`].
+///
+/// ```ignore
+/// common::examples! {
+///     Day07;
+///     small: SMALL_INPUT => part1 = 3749, part2 = 11387;
+///     dir "examples";
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty; $($rest:tt)*) => {
+        $crate::examples!(@examples $solution; $($rest)*);
+    };
+
+    (@examples $solution:ty;) => {};
+
+    (@examples $solution:ty;
+        $name:ident: $input:expr => $($part:ident = $expected:expr),+;
+        $($rest:tt)*
+    ) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                fn $part() {
+                    $crate::examples::$part::<$solution>($input, $expected);
+                }
+            )+
+        }
+        $crate::examples!(@examples $solution; $($rest)*);
+    };
+
+    (@examples $solution:ty; dir $dir:literal; $($rest:tt)*) => {
+        #[test]
+        fn examples_dir() {
+            let dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($dir);
+            $crate::examples::check_dir::<$solution>(dir);
+        }
+        $crate::examples!(@examples $solution; $($rest)*);
+    };
+}
+
+/// Asserts that part 1 of `S` gives `expected` for the example `text`.
+pub fn part1<S: Solution>(text: &str, expected: impl Display) {
+    let input = parse::<S>(text, "example");
+    assert_eq!(S::part1(&input).to_string(), expected.to_string());
+}
+
+/// Asserts that part 2 of `S` gives `expected` for the example `text`.
+pub fn part2<S: Solution>(text: &str, expected: impl Display) {
+    let input = parse::<S>(text, "example");
+    assert_eq!(S::part2(&input).to_string(), expected.to_string());
+}
+
+/// Checks `S` against every example in `dir`. Each `NAME.txt` is an example input, and its
+/// answers are read from `NAME.toml` in the format of [`Answers`]. Parts without an answer are
+/// not run, so an example can be for one part only.
+pub fn check_dir<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let mut examples: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", dir.display()))
+        .map(|entry| entry.expect("failed to read directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    examples.sort();
+    assert!(!examples.is_empty(), "no examples in {}", dir.display());
+
+    let mut failures = vec![];
+    for example in examples {
+        let answers_path = example.with_extension("toml");
+        let answers: Answers = read(&answers_path)
+            .parse()
+            .unwrap_or_else(|err| panic!("{}:{err}", answers_path.display()));
+        let input = parse::<S>(&read(&example), &example.display().to_string());
+
+        let mut check = |part, answer: String| {
+            if let Verdict::Fail { expected } = answers.check(part, &answer) {
+                failures.push(format!(
+                    "{}: part {part}: got {answer}, expected {expected}",
+                    example.display()
+                ));
+            }
+        };
+        if answers.part1.is_some() {
+            check(1, S::part1(&input).to_string());
+        }
+        if answers.part2.is_some() {
+            check(2, S::part2(&input).to_string());
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn parse<S: Solution>(text: &str, name: &str) -> S::Input {
+    S::parse(text).unwrap_or_else(|err| panic!("{name}:{err}"))
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()))
+}
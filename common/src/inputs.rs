@@ -0,0 +1,119 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// Where to look for a day's input when none is given explicitly.
+///
+/// The pattern is a path in which `{day}` is replaced by the zero-padded day number and
+/// `{variant}` by `.<name>` for a named variant such as `example`, or by nothing for the real
+/// input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputPattern {
+    pattern: String,
+}
+
+/// The variant name that designates the puzzle's real input.
+pub const REAL_VARIANT: &str = "real";
+
+impl InputPattern {
+    /// Environment variable overriding [`InputPattern::DEFAULT`].
+    pub const ENV_VAR: &str = "AOC_INPUT_PATTERN";
+    pub const DEFAULT: &str = "inputs/day-{day}{variant}.txt";
+
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(env::var(Self::ENV_VAR).unwrap_or_else(|_| Self::DEFAULT.to_owned()))
+    }
+
+    pub fn path(&self, day: u32, variant: Option<&str>) -> PathBuf {
+        let variant = match variant {
+            None | Some(REAL_VARIANT) => String::new(),
+            Some(name) => format!(".{name}"),
+        };
+        self.pattern
+            .replace("{day}", &format!("{day:02}"))
+            .replace("{variant}", &variant)
+            .into()
+    }
+}
+
+/// How an `--input` value should be understood.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputArg {
+    File(PathBuf),
+    Variant(String),
+}
+
+impl InputArg {
+    /// Treats `arg` as a file if it exists or looks like a path, and as a variant name
+    /// otherwise.
+    pub fn new(arg: String) -> Self {
+        let looks_like_path = arg.contains(['/', '\\', '.']);
+        if looks_like_path || Path::new(&arg).is_file() {
+            Self::File(arg.into())
+        } else {
+            Self::Variant(arg)
+        }
+    }
+}
+
+/// Extracts the day number from the name of a day's binary, such as `day-04` or `day-1.exe`.
+pub fn day_from_program(program: &str) -> Option<u32> {
+    let name = Path::new(program).file_stem()?.to_str()?;
+    let digits = name.strip_prefix("day-")?;
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+    digits[..end].parse().ok()
+}
+
+/// The day number of the running binary, if it is one of the day binaries.
+pub fn current_day() -> Option<u32> {
+    day_from_program(&env::args().next()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::inputs::{day_from_program, InputArg, InputPattern};
+
+    #[test]
+    fn test_path() {
+        let pattern = InputPattern::new(InputPattern::DEFAULT);
+        assert_eq!(pattern.path(7, None), PathBuf::from("inputs/day-07.txt"));
+        assert_eq!(
+            pattern.path(7, Some("real")),
+            PathBuf::from("inputs/day-07.txt")
+        );
+        assert_eq!(
+            pattern.path(12, Some("example")),
+            PathBuf::from("inputs/day-12.example.txt")
+        );
+    }
+
+    #[test]
+    fn test_day_from_program() {
+        assert_eq!(day_from_program("target/debug/day-04"), Some(4));
+        assert_eq!(day_from_program("day-1.exe"), Some(1));
+        assert_eq!(day_from_program("target/debug/runner"), None);
+    }
+
+    #[test]
+    fn test_input_arg() {
+        assert_eq!(
+            InputArg::new("example".into()),
+            InputArg::Variant("example".into())
+        );
+        assert_eq!(
+            InputArg::new("inputs/mine.txt".into()),
+            InputArg::File("inputs/mine.txt".into())
+        );
+    }
+}
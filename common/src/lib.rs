@@ -1,25 +1,34 @@
-use std::{
-    convert, env,
-    fmt::Display,
-    fs,
-    io::{self, Read},
-};
+use std::{convert, convert::Infallible, env, fmt::Display, process};
 
+use inputs::InputArg;
+use parse::ParseError;
+use runner::{Config, InputSource};
+pub use solution::Solution;
+
+pub mod answers;
 pub mod array2;
+pub mod examples;
+pub mod inputs;
 pub mod math;
+pub mod parse;
+pub mod report;
+pub mod runner;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+pub mod union_find;
 
+/// Reads the input file given as the first argument, or the input found for the current day
+/// by [`inputs::InputPattern`], or stdin.
 pub fn input() -> String {
-    match env::args().nth(1) {
-        Some(file) => fs::read_to_string(file).expect("failed to read input file"),
-        None => {
-            eprintln!("reading from stdin...");
-            let mut input = String::new();
-            io::stdin()
-                .read_to_string(&mut input)
-                .expect("failed to read from stdin");
-            input
-        }
-    }
+    let arg = env::args().nth(1).map(|file| InputArg::File(file.into()));
+    InputSource::locate(arg, inputs::current_day())
+        .expect("inputs given as files are always found")
+        .read()
+        .unwrap_or_else(|err| {
+            eprintln!("error: {err}");
+            process::exit(1);
+        })
 }
 
 pub fn run_with_parser<I, I1, I2, R1, R2>(
@@ -33,9 +42,46 @@ pub fn run_with_parser<I, I1, I2, R1, R2>(
     R1: Display,
     R2: Display,
 {
-    let input = parse(input());
-    println!("part 1: {}", part1(input.as_ref()));
-    println!("part 2: {}", part2(input.as_ref()));
+    try_run_with_parser(
+        |text| Ok::<_, ParseError<Infallible>>(parse(text)),
+        part1,
+        part2,
+    );
+}
+
+pub fn try_run_with_parser<I, E, I1, I2, R1, R2>(
+    parse: impl FnOnce(String) -> Result<I, ParseError<E>>,
+    part1: impl FnOnce(&I1) -> R1,
+    part2: impl FnOnce(&I2) -> R2,
+) where
+    I: AsRef<I1> + AsRef<I2>,
+    E: Display,
+    I1: ?Sized,
+    I2: ?Sized,
+    R1: Display,
+    R2: Display,
+{
+    run_from_args(|config| config.try_run_once(parse, part1, part2));
+}
+
+/// Runs the day implemented by `S` as configured on the command line.
+pub fn run_solution<S: Solution>() {
+    run_from_args(Config::run_solution::<S>);
+}
+
+fn run_from_args(run: impl FnOnce(&Config) -> Result<report::Report, runner::RunError>) {
+    let config = Config::from_args();
+    if let Some(header) = config.format.header() {
+        println!("{header}");
+    }
+    match run(&config) {
+        Ok(report) if report.passed() => {}
+        Ok(_) => process::exit(1),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
 }
 
 pub fn run<I1, I2, R1, R2>(part1: impl FnOnce(&I1) -> R1, part2: impl FnOnce(&I2) -> R2)
@@ -1,44 +1,299 @@
-use std::ops::*;
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::*,
+};
+
+pub use linear::{solve_linear, AffineSpace};
+pub use number::{
+    concat_digits, crt, digit_count, extended_gcd, gcd, lcm, mod_inverse, split_digits,
+    strip_digit_suffix,
+};
+pub use rational::Rational;
+
+mod linear;
+mod number;
+mod rational;
+
+/// A primitive signed integer type that [`Pos`] and [`Vect`] can be made of.
+pub trait Int:
+    Copy
+    + Debug
+    + Display
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + TryInto<usize>
+{
+    /// The unsigned type of the same width, which can hold the absolute value of any `Self`.
+    type Unsigned: Copy + Debug + Ord + Add<Output = Self::Unsigned>;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn unsigned_abs(self) -> Self::Unsigned;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+}
+
+macro_rules! impl_int {
+    ($($int:ty => $unsigned:ty),* $(,)?) => {$(
+        impl Int for $int {
+            type Unsigned = $unsigned;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                self.abs()
+            }
+            fn unsigned_abs(self) -> Self::Unsigned {
+                self.unsigned_abs()
+            }
+            fn rem_euclid(self, rhs: Self) -> Self {
+                self.rem_euclid(rhs)
+            }
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.checked_sub(rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                self.checked_div(rhs)
+            }
+            fn checked_neg(self) -> Option<Self> {
+                self.checked_neg()
+            }
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_add(rhs)
+            }
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_sub(rhs)
+            }
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_mul(rhs)
+            }
+        }
+
+        impl Mul<Vect<$int>> for $int {
+            type Output = Vect<$int>;
+            fn mul(self, rhs: Vect<$int>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )*};
+}
+
+impl_int!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Pos {
-    pub x: isize,
-    pub y: isize,
+pub struct Pos<T = isize> {
+    pub x: T,
+    pub y: T,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Vect {
-    pub x: isize,
-    pub y: isize,
+pub struct Vect<T = isize> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Pos {
-    pub fn new(x: isize, y: isize) -> Self {
+impl<T: Int> Pos<T> {
+    pub const ORIGIN: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
-    pub fn in_bounds(self, width: usize, height: usize) -> bool {
-        usize::try_from(self.x).ok().is_some_and(|x| x < width)
-            && usize::try_from(self.y).ok().is_some_and(|y| y < height)
+    /// Whether the position lies in a grid of the given `(width, height)`, such as the
+    /// [`size`](crate::array2::Array2::size) of an `Array2`.
+    pub fn within(self, (width, height): (usize, usize)) -> bool {
+        self.x.try_into().ok().is_some_and(|x| x < width)
+            && self.y.try_into().ok().is_some_and(|y| y < height)
     }
 
-    pub fn rem_euclid(self, x: isize, y: isize) -> Self {
+    pub fn rem_euclid(self, x: T, y: T) -> Self {
         Self {
             x: self.x.rem_euclid(x),
             y: self.y.rem_euclid(y),
         }
     }
+
+    /// The number of orthogonal steps between the two positions.
+    pub fn manhattan(self, other: Self) -> T::Unsigned {
+        (other - self).manhattan()
+    }
+
+    /// The number of orthogonal or diagonal steps between the two positions.
+    pub fn chebyshev(self, other: Self) -> T::Unsigned {
+        (other - self).chebyshev()
+    }
+
+    /// `self + rhs`, or `None` if a coordinate overflows.
+    pub fn checked_add(self, rhs: Vect<T>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+        ))
+    }
+
+    /// `self - rhs`, or `None` if a coordinate overflows.
+    pub fn checked_sub(self, rhs: Vect<T>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    /// The vector from `rhs` to `self`, or `None` if a component overflows.
+    pub fn checked_offset_from(self, rhs: Self) -> Option<Vect<T>> {
+        Some(Vect::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    /// `self + rhs` with wrapping coordinates, along with whether any of them overflowed.
+    pub fn overflowing_add(self, rhs: Vect<T>) -> (Self, bool) {
+        let (x, x_overflowed) = self.x.overflowing_add(rhs.x);
+        let (y, y_overflowed) = self.y.overflowing_add(rhs.y);
+        (Self::new(x, y), x_overflowed || y_overflowed)
+    }
 }
 
-impl Vect {
-    pub fn new(x: isize, y: isize) -> Self {
+impl<T: Int> Vect<T> {
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
+    pub fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
+
+    /// The length in orthogonal steps.
+    pub fn manhattan(self) -> T::Unsigned {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// The length in orthogonal or diagonal steps.
+    pub fn chebyshev(self) -> T::Unsigned {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The `z` component of the cross product, positive if `other` is clockwise from `self` on
+    /// a grid where `y` grows downwards.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Rotates a quarter turn clockwise on a grid where `y` grows downwards.
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn counterclockwise on a grid where `y` grows downwards.
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The shortest vector with the same direction, whose components are coprime. The zero
+    /// vector stays as is.
+    pub fn reduced(self) -> Self {
+        let divisor = gcd(self.x, self.y);
+        if divisor == T::ZERO {
+            self
+        } else {
+            self / divisor
+        }
+    }
+
+    /// `self + rhs`, or `None` if a component overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+        ))
+    }
+
+    /// `self - rhs`, or `None` if a component overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    /// `self * rhs`, or `None` if a component overflows.
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(rhs)?,
+            self.y.checked_mul(rhs)?,
+        ))
+    }
+
+    /// `self / rhs`, or `None` if `rhs` is zero or a component overflows.
+    pub fn checked_div(self, rhs: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_div(rhs)?,
+            self.y.checked_div(rhs)?,
+        ))
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(self.x.checked_neg()?, self.y.checked_neg()?))
+    }
+
+    /// Like [`Vect::dot`], or `None` if it overflows.
+    pub fn checked_dot(self, other: Self) -> Option<T> {
+        self.x
+            .checked_mul(other.x)?
+            .checked_add(self.y.checked_mul(other.y)?)
+    }
+
+    /// Like [`Vect::cross`], or `None` if it overflows.
+    pub fn checked_cross(self, other: Self) -> Option<T> {
+        self.x
+            .checked_mul(other.y)?
+            .checked_sub(self.y.checked_mul(other.x)?)
+    }
+
+    /// `self * rhs` with wrapping components, along with whether any of them overflowed.
+    pub fn overflowing_mul(self, rhs: T) -> (Self, bool) {
+        let (x, x_overflowed) = self.x.overflowing_mul(rhs);
+        let (y, y_overflowed) = self.y.overflowing_mul(rhs);
+        (Self::new(x, y), x_overflowed || y_overflowed)
+    }
 }
 
-impl Add<Vect> for Pos {
-    type Output = Pos;
-    fn add(self, rhs: Vect) -> Self::Output {
+impl<T: Int> Add<Vect<T>> for Pos<T> {
+    type Output = Pos<T>;
+    fn add(self, rhs: Vect<T>) -> Self::Output {
         Self {
             x: self.x + rhs.x,
             y: self.y + rhs.y,
@@ -46,16 +301,16 @@ impl Add<Vect> for Pos {
     }
 }
 
-impl Add<Pos> for Vect {
-    type Output = Pos;
-    fn add(self, rhs: Pos) -> Self::Output {
+impl<T: Int> Add<Pos<T>> for Vect<T> {
+    type Output = Pos<T>;
+    fn add(self, rhs: Pos<T>) -> Self::Output {
         rhs + self
     }
 }
 
-impl Sub<Vect> for Pos {
-    type Output = Pos;
-    fn sub(self, rhs: Vect) -> Self::Output {
+impl<T: Int> Sub<Vect<T>> for Pos<T> {
+    type Output = Pos<T>;
+    fn sub(self, rhs: Vect<T>) -> Self::Output {
         Self {
             x: self.x - rhs.x,
             y: self.y - rhs.y,
@@ -63,9 +318,53 @@ impl Sub<Vect> for Pos {
     }
 }
 
-impl Sub<Pos> for Pos {
-    type Output = Vect;
-    fn sub(self, rhs: Pos) -> Self::Output {
+impl<T: Int> AddAssign<Vect<T>> for Pos<T> {
+    fn add_assign(&mut self, rhs: Vect<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Int> SubAssign<Vect<T>> for Pos<T> {
+    fn sub_assign(&mut self, rhs: Vect<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Int> Add<Vect<T>> for Vect<T> {
+    type Output = Vect<T>;
+    fn add(self, rhs: Vect<T>) -> Self::Output {
+        Vect {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Int> Sub<Vect<T>> for Vect<T> {
+    type Output = Vect<T>;
+    fn sub(self, rhs: Vect<T>) -> Self::Output {
+        Vect {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Int> AddAssign<Vect<T>> for Vect<T> {
+    fn add_assign(&mut self, rhs: Vect<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Int> SubAssign<Vect<T>> for Vect<T> {
+    fn sub_assign(&mut self, rhs: Vect<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Int> Sub<Pos<T>> for Pos<T> {
+    type Output = Vect<T>;
+    fn sub(self, rhs: Pos<T>) -> Self::Output {
         Vect {
             x: self.x - rhs.x,
             y: self.y - rhs.y,
@@ -73,25 +372,41 @@ impl Sub<Pos> for Pos {
     }
 }
 
-impl Mul<Vect> for isize {
-    type Output = Vect;
-    fn mul(self, rhs: Vect) -> Self::Output {
+impl<T: Int> Mul<T> for Vect<T> {
+    type Output = Vect<T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        Vect {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Int> MulAssign<T> for Vect<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+/// Divides both components, rounding towards zero.
+impl<T: Int> Div<T> for Vect<T> {
+    type Output = Vect<T>;
+    fn div(self, rhs: T) -> Self::Output {
         Vect {
-            x: self * rhs.x,
-            y: self * rhs.y,
+            x: self.x / rhs,
+            y: self.y / rhs,
         }
     }
 }
 
-impl Mul<isize> for Vect {
-    type Output = Vect;
-    fn mul(self, rhs: isize) -> Self::Output {
-        rhs * self
+impl<T: Int> DivAssign<T> for Vect<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
     }
 }
 
-impl Neg for Vect {
-    type Output = Vect;
+impl<T: Int> Neg for Vect<T> {
+    type Output = Vect<T>;
     fn neg(self) -> Self::Output {
         Vect {
             x: -self.x,
@@ -99,3 +414,336 @@ impl Neg for Vect {
         }
     }
 }
+
+/// One of the four orthogonal directions, with `y` growing downwards as in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the eight orthogonal and diagonal directions, with `y` growing downwards as in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir4 {
+    /// All directions, clockwise from up. A direction's [`index`](Dir4::index) is its position
+    /// here.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn vect(self) -> Vect {
+        match self {
+            Self::Up => Vect::new(0, -1),
+            Self::Right => Vect::new(1, 0),
+            Self::Down => Vect::new(0, 1),
+            Self::Left => Vect::new(-1, 0),
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn cw(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    /// The direction a quarter turn counterclockwise.
+    pub fn ccw(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// Parses an arrow (`^>v<`) or a compass letter (`NESW`), with north being up.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Self::Up),
+            '>' | 'E' => Some(Self::Right),
+            'v' | 'S' => Some(Self::Down),
+            '<' | 'W' => Some(Self::Left),
+            _ => None,
+        }
+    }
+}
+
+impl Dir8 {
+    /// All directions, clockwise from up. A direction's [`index`](Dir8::index) is its position
+    /// here.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn vect(self) -> Vect {
+        match self {
+            Self::Up => Vect::new(0, -1),
+            Self::UpRight => Vect::new(1, -1),
+            Self::Right => Vect::new(1, 0),
+            Self::DownRight => Vect::new(1, 1),
+            Self::Down => Vect::new(0, 1),
+            Self::DownLeft => Vect::new(-1, 1),
+            Self::Left => Vect::new(-1, 0),
+            Self::UpLeft => Vect::new(-1, -1),
+        }
+    }
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn cw(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn counterclockwise.
+    pub fn ccw(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// Parses an arrow (`^>v<`) or a compass point (`N`, `NE`, `E`, ..., `NW`), with north
+    /// being up.
+    pub fn from_compass(s: &str) -> Option<Self> {
+        let mut chars = s.chars();
+        match (chars.next()?, chars.next(), chars.next()) {
+            (c, None, _) => Dir4::from_char(c).map(Self::from),
+            ('N', Some('E'), None) => Some(Self::UpRight),
+            ('S', Some('E'), None) => Some(Self::DownRight),
+            ('S', Some('W'), None) => Some(Self::DownLeft),
+            ('N', Some('W'), None) => Some(Self::UpLeft),
+            _ => None,
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir.index() * 2]
+    }
+}
+
+impl From<Dir4> for Vect {
+    fn from(dir: Dir4) -> Self {
+        dir.vect()
+    }
+}
+
+impl From<Dir8> for Vect {
+    fn from(dir: Dir8) -> Self {
+        dir.vect()
+    }
+}
+
+impl<T> Index<Dir4> for [T; 4] {
+    type Output = T;
+    fn index(&self, dir: Dir4) -> &T {
+        &self[dir.index()]
+    }
+}
+
+impl<T> IndexMut<Dir4> for [T; 4] {
+    fn index_mut(&mut self, dir: Dir4) -> &mut T {
+        &mut self[dir.index()]
+    }
+}
+
+impl<T> Index<Dir8> for [T; 8] {
+    type Output = T;
+    fn index(&self, dir: Dir8) -> &T {
+        &self[dir.index()]
+    }
+}
+
+impl<T> IndexMut<Dir8> for [T; 8] {
+    fn index_mut(&mut self, dir: Dir8) -> &mut T {
+        &mut self[dir.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{Dir4, Dir8, Pos, Vect};
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Vect::new(3, -2), Vect::new(-1, 4));
+        assert_eq!(a + b, Vect::new(2, 2));
+        assert_eq!(a - b, Vect::new(4, -6));
+        assert_eq!(
+            Vect::new(-1, 0) - Vect::new(isize::MIN, 0),
+            Vect::new(isize::MAX, 0)
+        );
+        assert_eq!(2 * a, a * 2);
+        assert_eq!((a * 3) / 3, a);
+        assert_eq!(Vect::new(7, -7) / 2, Vect::new(3, -3));
+
+        let mut pos = Pos::ORIGIN;
+        pos += a;
+        pos -= b;
+        assert_eq!(pos, Pos::new(4, -6));
+        let mut v = a;
+        v += b;
+        v -= a;
+        v *= 2;
+        v /= -2;
+        assert_eq!(v, -b);
+        assert_eq!(Pos::<isize>::ORIGIN + Vect::ZERO, Pos::ORIGIN);
+    }
+
+    #[test]
+    fn test_products_and_rotation() {
+        let (right, down) = (Vect::new(1, 0), Vect::new(0, 1));
+        assert_eq!(right.dot(down), 0);
+        assert_eq!(Vect::new(2, 3).dot(Vect::new(4, -1)), 5);
+        assert_eq!(right.cross(down), 1);
+        assert_eq!(down.cross(right), -1);
+        assert_eq!(right.rotate_cw(), down);
+        assert_eq!(down.rotate_ccw(), right);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.vect().rotate_cw(), dir.cw().vect());
+            assert_eq!(dir.vect().rotate_ccw(), dir.ccw().vect());
+        }
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b): (Pos, Pos) = (Pos::new(1, 5), Pos::new(-2, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Vect::<i64>::ZERO.manhattan(), 0);
+    }
+
+    #[test]
+    fn test_reduced() {
+        assert_eq!(Vect::new(12, -9).reduced(), Vect::new(4, -3));
+        assert_eq!(Vect::new(-6, -4).reduced(), Vect::new(-3, -2));
+        assert_eq!(Vect::new(0, 6).reduced(), Vect::new(0, 1));
+        assert_eq!(Vect::new(-5, 0).reduced(), Vect::new(-1, 0));
+        assert_eq!(Vect::new(4, 7).reduced(), Vect::new(4, 7));
+        assert_eq!(Vect::<isize>::ZERO.reduced(), Vect::ZERO);
+    }
+
+    #[test]
+    fn test_generic() {
+        let pos: Pos<i64> = Pos::new(3_000_000_000_000, -4);
+        let step: Vect<i64> = Vect::new(2, 1);
+        assert_eq!(
+            pos + step * 1_000_000_000_000,
+            Pos::new(5_000_000_000_000, 999_999_999_996)
+        );
+        assert_eq!(Pos::<i32>::new(3, 4).manhattan(Pos::ORIGIN), 7u32);
+        assert_eq!(Vect::<i8>::new(-128, 0).chebyshev(), 128u8);
+        assert_eq!(Vect::<i32>::new(-6, 9).reduced(), Vect::new(-2, 3));
+        assert!(Pos::<i16>::new(2, 1).within((3, 2)));
+        assert!(!Pos::<i16>::new(-1, 1).within((3, 2)));
+    }
+
+    #[test]
+    fn test_checked() {
+        let max = Pos::<i32>::new(i32::MAX, 0);
+        assert_eq!(max.checked_add(Vect::new(1, 0)), None);
+        assert_eq!(
+            max.checked_sub(Vect::new(1, 0)),
+            Some(Pos::new(i32::MAX - 1, 0))
+        );
+        assert_eq!(Pos::ORIGIN.checked_sub(Vect::new(0, i32::MIN)), None);
+        assert_eq!(
+            max.overflowing_add(Vect::new(1, 1)),
+            (Pos::new(i32::MIN, 1), true)
+        );
+        assert_eq!(
+            Pos::new(i32::MIN, 0).checked_offset_from(Pos::new(1, 0)),
+            None
+        );
+
+        let v = Vect::<i32>::new(1 << 20, -3);
+        assert_eq!(v.checked_mul(1 << 11), None);
+        assert_eq!(v.checked_mul(4), Some(Vect::new(1 << 22, -12)));
+        assert_eq!(v.checked_div(0), None);
+        assert_eq!(
+            v.checked_add(Vect::new(1, 1)),
+            Some(Vect::new((1 << 20) + 1, -2))
+        );
+        assert_eq!(Vect::new(i32::MIN, 0).checked_neg(), None);
+        assert_eq!(v.checked_dot(v), None);
+        assert_eq!(v.checked_cross(Vect::new(0, 1 << 12)), None);
+        assert_eq!(
+            Vect::<i32>::new(2, 3).checked_cross(Vect::new(4, 5)),
+            Some(-2)
+        );
+        assert_eq!(v.overflowing_mul(1 << 12), (Vect::new(0, -12288), true));
+    }
+
+    #[test]
+    fn test_dir4() {
+        assert_eq!(Dir4::Up.cw(), Dir4::Right);
+        assert_eq!(Dir4::Up.ccw(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.cw().ccw(), dir);
+            assert_eq!(-dir.vect(), dir.opposite().vect());
+        }
+        assert_eq!(Pos::new(2, 2) + Dir4::Down.vect(), Pos::new(2, 3));
+
+        let dirs: Vec<Dir4> = "^>v<NESW".chars().filter_map(Dir4::from_char).collect();
+        assert_eq!(dirs[..4], Dir4::ALL);
+        assert_eq!(dirs[4..], Dir4::ALL);
+        assert_eq!(Dir4::from_char('x'), None);
+
+        let mut visits = [0; 4];
+        visits[Dir4::Left] += 1;
+        assert_eq!(visits, [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_dir8() {
+        assert_eq!(Dir8::Up.cw(), Dir8::UpRight);
+        assert_eq!(Dir8::Up.ccw(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownLeft.opposite(), Dir8::UpRight);
+        assert_eq!(Vect::from(Dir8::DownLeft), Vect::new(-1, 1));
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+        assert!(Dir8::UpLeft.is_diagonal());
+        assert!(!Dir8::Down.is_diagonal());
+        for dir in Dir8::ALL {
+            assert_eq!(dir.cw().ccw(), dir);
+            assert_eq!(-dir.vect(), dir.opposite().vect());
+        }
+
+        assert_eq!(Dir8::from_compass("NW"), Some(Dir8::UpLeft));
+        assert_eq!(Dir8::from_compass("v"), Some(Dir8::Down));
+        assert_eq!(Dir8::from_compass("E"), Some(Dir8::Right));
+        assert_eq!(Dir8::from_compass("EN"), None);
+        assert_eq!(Dir8::from_compass("NEE"), None);
+        assert_eq!(Dir8::from_compass(""), None);
+    }
+}
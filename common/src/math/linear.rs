@@ -0,0 +1,245 @@
+use std::cmp::Ordering;
+
+use crate::math::{crt, gcd, mod_inverse, Int, Rational};
+
+/// The solutions of a system of linear equations: `point` plus any combination of
+/// `directions`, with one direction per free variable. There is a single solution if there
+/// are no directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AffineSpace<T = i64> {
+    pub point: Vec<Rational<T>>,
+    pub directions: Vec<Vec<Rational<T>>>,
+}
+
+/// Solves `matrix * x = rhs` exactly by Gauss-Jordan elimination, where `matrix` has one row
+/// per equation and one column per unknown. Returns `None` if the equations are inconsistent.
+///
+/// # Panics
+///
+/// If the rows of `matrix` don't all have the same length, or there isn't one `rhs` per row.
+pub fn solve_linear<T: Int>(matrix: &[Vec<T>], rhs: &[T]) -> Option<AffineSpace<T>> {
+    assert_eq!(matrix.len(), rhs.len(), "one right-hand side per equation");
+    let n_unknowns = matrix.first().map_or(0, Vec::len);
+    let mut rows: Vec<Vec<Rational<T>>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &rhs)| {
+            assert_eq!(row.len(), n_unknowns, "rows of different lengths");
+            row.iter()
+                .chain([&rhs])
+                .map(|&n| Rational::from(n))
+                .collect()
+        })
+        .collect();
+
+    // reduce to row echelon form with every pivot being 1 and alone in its column
+    let mut pivots = vec![];
+    for column in 0..n_unknowns {
+        let row = pivots.len();
+        let Some(pivot_row) = (row..rows.len()).find(|&r| rows[r][column] != Rational::ZERO) else {
+            continue;
+        };
+        rows.swap(row, pivot_row);
+
+        let pivot = rows[row][column];
+        for value in &mut rows[row] {
+            *value = *value / pivot;
+        }
+        let pivot_row = rows[row].clone();
+        for (other, values) in rows.iter_mut().enumerate() {
+            let factor = values[column];
+            if other == row || factor == Rational::ZERO {
+                continue;
+            }
+            for (value, &pivot_value) in values.iter_mut().zip(&pivot_row).skip(column) {
+                *value = *value - factor * pivot_value;
+            }
+        }
+        pivots.push(column);
+    }
+
+    // a remaining row reads 0 = rhs
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| row[n_unknowns] != Rational::ZERO)
+    {
+        return None;
+    }
+
+    let mut point = vec![Rational::ZERO; n_unknowns];
+    for (row, &column) in pivots.iter().enumerate() {
+        point[column] = rows[row][n_unknowns];
+    }
+    let directions = (0..n_unknowns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut direction = vec![Rational::ZERO; n_unknowns];
+            direction[free] = Rational::ONE;
+            for (row, &column) in pivots.iter().enumerate() {
+                direction[column] = -rows[row][free];
+            }
+            direction
+        })
+        .collect();
+    Some(AffineSpace { point, directions })
+}
+
+impl<T: Int> AffineSpace<T> {
+    /// The solution whose values are all non-negative integers with the least total `cost`,
+    /// where `cost` has one weight per unknown. Ties go to the solution with the smallest free
+    /// variable.
+    ///
+    /// Only a single solution or a line of solutions is supported: returns `None` if there's
+    /// more than one free variable, as well as if there's no such solution or no least cost.
+    pub fn min_cost_non_negative_integer(&self, cost: &[T]) -> Option<Vec<T>> {
+        assert_eq!(cost.len(), self.point.len(), "one cost per unknown");
+        match self.directions.as_slice() {
+            [] => self
+                .point
+                .iter()
+                .map(|&value| value.to_integer().filter(|&n| n >= T::ZERO))
+                .collect(),
+            [direction] => {
+                let t = self.min_cost_parameter(direction, cost)?;
+                self.point
+                    .iter()
+                    .zip(direction)
+                    .map(|(&p, &d)| (p + d * Rational::from(t)).to_integer())
+                    .collect()
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds the best `t` for the solutions `point + t * direction`. As `t` is the value of the
+    /// free variable, it must be an integer.
+    fn min_cost_parameter(&self, direction: &[Rational<T>], cost: &[T]) -> Option<T> {
+        // bounds on t for every value to be non-negative
+        let (mut low, mut high): (Option<T>, Option<T>) = (None, None);
+        // residue classes of t for every value to be an integer
+        let mut congruences = vec![];
+
+        for (&p, &d) in self.point.iter().zip(direction) {
+            match d.signum() {
+                Ordering::Equal if p < Rational::ZERO || !p.is_integer() => return None,
+                Ordering::Equal => continue,
+                Ordering::Greater => {
+                    let bound = (-p / d).ceil();
+                    low = Some(low.map_or(bound, |low| low.max(bound)));
+                }
+                Ordering::Less => {
+                    let bound = (-p / d).floor();
+                    high = Some(high.map_or(bound, |high| high.min(bound)));
+                }
+            }
+            congruences.push(integer_congruence(p, d)?);
+        }
+        let (residue, modulus) = crt(congruences)?;
+
+        let slope = direction
+            .iter()
+            .zip(cost)
+            .fold(Rational::ZERO, |slope, (&d, &c)| {
+                slope + d * Rational::from(c)
+            });
+        let t = match (slope.signum(), low, high) {
+            (Ordering::Less, _, None) => return None,
+            (Ordering::Less, _, Some(high)) => high - (high - residue).rem_euclid(modulus),
+            (_, None, _) => return None,
+            (_, Some(low), _) => low + (residue - low).rem_euclid(modulus),
+        };
+        let fits = low.is_none_or(|low| t >= low) && high.is_none_or(|high| t <= high);
+        fits.then_some(t)
+    }
+}
+
+/// The `t` for which `p + t * d` is an integer, as `(residue, modulus)` for [`crt`].
+fn integer_congruence<T: Int>(p: Rational<T>, d: Rational<T>) -> Option<(T, T)> {
+    // scaled by the common denominator, p + t * d is an integer if
+    // p' + t * d' ≡ 0 (mod denominator)
+    let denominator = p.denominator() / gcd(p.denominator(), d.denominator()) * d.denominator();
+    let p_scaled = p.numerator() * (denominator / p.denominator());
+    let d_scaled = d.numerator() * (denominator / d.denominator());
+
+    let divisor = gcd(d_scaled, denominator);
+    if p_scaled % divisor != T::ZERO {
+        return None;
+    }
+    let modulus = denominator / divisor;
+    let inverse = mod_inverse(d_scaled / divisor, modulus)?;
+    let residue = (-p_scaled / divisor).rem_euclid(modulus) * inverse % modulus;
+    Some((residue, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{solve_linear, AffineSpace, Rational};
+
+    fn ints(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|&n| Rational::from(n)).collect()
+    }
+
+    #[test]
+    fn test_unique() {
+        let matrix = [vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        let solution = solve_linear(&matrix, &[8, -11, -3]).unwrap();
+        assert_eq!(solution.point, ints(&[2, 3, -1]));
+        assert!(solution.directions.is_empty());
+
+        let solution = solve_linear(&[vec![2, 0], vec![0, 3]], &[1, 1]).unwrap();
+        assert_eq!(solution.point, [Rational::new(1, 2), Rational::new(1, 3)]);
+        assert_eq!(solution.min_cost_non_negative_integer(&[1, 1]), None);
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        assert_eq!(solve_linear(&[vec![1, 2], vec![2, 4]], &[3, 7]), None);
+    }
+
+    #[test]
+    fn test_line() {
+        let solution = solve_linear(&[vec![2, 3], vec![4, 6]], &[12, 24]).unwrap();
+        assert_eq!(
+            solution,
+            AffineSpace {
+                point: ints(&[6, 0]),
+                directions: vec![vec![Rational::new(-3, 2), Rational::ONE]],
+            }
+        );
+        assert_eq!(
+            solution.min_cost_non_negative_integer(&[3, 1]),
+            Some(vec![0, 4])
+        );
+        assert_eq!(
+            solution.min_cost_non_negative_integer(&[1, 3]),
+            Some(vec![6, 0])
+        );
+        assert_eq!(
+            solution.min_cost_non_negative_integer(&[2, 3]),
+            Some(vec![6, 0])
+        );
+
+        let odd = solve_linear(&[vec![2, 4]], &[7]).unwrap();
+        assert_eq!(odd.min_cost_non_negative_integer(&[1, 1]), None);
+
+        let unbounded = solve_linear(&[vec![1, -1]], &[1]).unwrap();
+        assert_eq!(
+            unbounded.min_cost_non_negative_integer(&[1, 1]),
+            Some(vec![1, 0])
+        );
+        assert_eq!(unbounded.min_cost_non_negative_integer(&[-1, 0]), None);
+
+        let plane = solve_linear(&[vec![1, 1, 1]], &[3]).unwrap();
+        assert_eq!(plane.directions.len(), 2);
+        assert_eq!(plane.min_cost_non_negative_integer(&[1, 1, 1]), None);
+    }
+
+    #[test]
+    fn test_large() {
+        let target: i64 = 10_000_000_000_000 + 8400;
+        let solution = solve_linear(&[vec![94, 22], vec![34, 67]], &[target, target - 3000]);
+        let solution = solution.unwrap();
+        assert!(solution.directions.is_empty());
+        assert_eq!(solution.min_cost_non_negative_integer(&[3, 1]), None);
+    }
+}
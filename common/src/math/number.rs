@@ -0,0 +1,171 @@
+use crate::math::Int;
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, which is never negative. It is 0 if either number is.
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Returns `(g, x, y)` where `g` is the [`gcd`] of `a` and `b` and `a * x + b * y == g`.
+pub fn extended_gcd<T: Int>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or `None` if `a` and `modulus`
+/// aren't coprime. `modulus` must be positive.
+pub fn mod_inverse<T: Int>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for each `(residue, modulus)`, returning
+/// `(x, m)` such that the solutions are exactly the numbers congruent to `x` modulo `m`, with
+/// `x` in `0..m`. The moduli must be positive but don't need to be coprime. Returns `None` if
+/// the congruences contradict each other.
+pub fn crt<T: Int>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let mut solution = (T::ZERO, T::ONE);
+    for (residue, modulus) in congruences {
+        let (x, m) = solution;
+        let g = gcd(m, modulus);
+        let difference = residue - x;
+        if difference % g != T::ZERO {
+            return None;
+        }
+
+        // x + m * k ≡ residue (mod modulus), so k ≡ (difference / g) / (m / g) (mod modulus / g)
+        let reduced_modulus = modulus / g;
+        let inverse = mod_inverse(m / g, reduced_modulus)?;
+        let k = (difference / g).rem_euclid(reduced_modulus) * inverse % reduced_modulus;
+        let combined_modulus = m * reduced_modulus;
+        solution = ((x + m * k).rem_euclid(combined_modulus), combined_modulus);
+    }
+    Some(solution)
+}
+
+/// The number of decimal digits, with 0 having one digit.
+pub fn digit_count(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// Splits a number with an even number of digits into its first and second half, such as
+/// `1234` into `(12, 34)` and `1000` into `(10, 0)`. Returns `None` for an odd number of digits.
+pub fn split_digits(n: u64) -> Option<(u64, u64)> {
+    let count = digit_count(n);
+    if !count.is_multiple_of(2) {
+        return None;
+    }
+    let half = 10u64.pow(count / 2);
+    Some((n / half, n % half))
+}
+
+/// Writes the digits of `b` after those of `a`, such as `12` and `345` into `12345`, or
+/// returns `None` on overflow.
+pub fn concat_digits(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(10u64.checked_pow(digit_count(b))?)?
+        .checked_add(b)
+}
+
+/// The inverse of [`concat_digits`]: if `n` ends with the digits of `suffix`, returns the
+/// number formed by the digits before them, such as `12` for `12345` and `345`.
+pub fn strip_digit_suffix(n: u64, suffix: u64) -> Option<u64> {
+    let Some(power) = 10u64.checked_pow(digit_count(suffix)) else {
+        return (n == suffix).then_some(0);
+    };
+    (n % power == suffix).then_some(n / power)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{
+        concat_digits, crt, digit_count, extended_gcd, gcd, lcm, mod_inverse, split_digits,
+        strip_digit_suffix,
+    };
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, -5), 5);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(101i64, 103), 10403);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 5), (0, 7), (7, 0), (-3, -9)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b), "gcd of {a} and {b}");
+            assert_eq!(a * x + b * y, g, "coefficients for {a} and {b}");
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(101, 103), Some(51));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 101i64), (5, 103)]), Some((10099, 10403)));
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt::<i32>([]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digit_count(0), 1);
+        assert_eq!(digit_count(9), 1);
+        assert_eq!(digit_count(10), 2);
+        assert_eq!(digit_count(u64::MAX), 20);
+
+        assert_eq!(split_digits(1234), Some((12, 34)));
+        assert_eq!(split_digits(1000), Some((10, 0)));
+        assert_eq!(split_digits(123), None);
+        assert_eq!(split_digits(0), None);
+
+        assert_eq!(concat_digits(12, 345), Some(12345));
+        assert_eq!(concat_digits(12, 0), Some(120));
+        assert_eq!(concat_digits(0, 7), Some(7));
+        assert_eq!(concat_digits(u64::MAX, 1), None);
+        assert_eq!(concat_digits(1, u64::MAX), None);
+
+        assert_eq!(strip_digit_suffix(12345, 345), Some(12));
+        assert_eq!(strip_digit_suffix(12345, 45), Some(123));
+        assert_eq!(strip_digit_suffix(12345, 12345), Some(0));
+        assert_eq!(strip_digit_suffix(12345, 346), None);
+        assert_eq!(strip_digit_suffix(120, 0), Some(12));
+        assert_eq!(strip_digit_suffix(u64::MAX, u64::MAX), Some(0));
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::math::{gcd, Int};
+
+/// An exact fraction, always stored in lowest terms with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i64> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Int> Rational<T> {
+    pub const ZERO: Self = Self {
+        numerator: T::ZERO,
+        denominator: T::ONE,
+    };
+
+    pub const ONE: Self = Self {
+        numerator: T::ONE,
+        denominator: T::ONE,
+    };
+
+    /// # Panics
+    ///
+    /// If `denominator` is zero.
+    pub fn new(numerator: T, denominator: T) -> Self {
+        assert!(denominator != T::ZERO, "`Rational` with a zero denominator");
+        let divisor = gcd(numerator, denominator);
+        let divisor = if denominator < T::ZERO {
+            -divisor
+        } else {
+            divisor
+        };
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn from_int(n: T) -> Self {
+        Self {
+            numerator: n,
+            denominator: T::ONE,
+        }
+    }
+
+    pub fn numerator(self) -> T {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> T {
+        self.denominator
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == T::ONE
+    }
+
+    /// The value as an integer, or `None` if it has a fractional part.
+    pub fn to_integer(self) -> Option<T> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// The greatest integer not above the value.
+    pub fn floor(self) -> T {
+        let remainder = self.numerator.rem_euclid(self.denominator);
+        (self.numerator - remainder) / self.denominator
+    }
+
+    /// The least integer not below the value.
+    pub fn ceil(self) -> T {
+        -(-self).floor()
+    }
+
+    pub fn signum(self) -> Ordering {
+        self.numerator.cmp(&T::ZERO)
+    }
+
+    pub fn recip(self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+}
+
+impl<T: Int> From<T> for Rational<T> {
+    fn from(n: T) -> Self {
+        Self::from_int(n)
+    }
+}
+
+impl<T: Int> Add for Rational<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let divisor = gcd(self.denominator, rhs.denominator);
+        Self::new(
+            self.numerator * (rhs.denominator / divisor)
+                + rhs.numerator * (self.denominator / divisor),
+            self.denominator / divisor * rhs.denominator,
+        )
+    }
+}
+
+impl<T: Int> Sub for Rational<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<T: Int> Mul for Rational<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        // cancel crosswise first to keep the products small
+        let a = gcd(self.numerator, rhs.denominator);
+        let b = gcd(rhs.numerator, self.denominator);
+        if a == T::ZERO || b == T::ZERO {
+            return Self::ZERO;
+        }
+        Self::new(
+            (self.numerator / a) * (rhs.numerator / b),
+            (self.denominator / b) * (rhs.denominator / a),
+        )
+    }
+}
+
+/// # Panics
+///
+/// If `rhs` is zero.
+impl<T: Int> Div for Rational<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Self::mul(self, rhs.recip())
+    }
+}
+
+impl<T: Int> Neg for Rational<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl<T: Int> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Int> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (*self - *other).signum()
+    }
+}
+
+impl<T: Int> Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::math::Rational;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        assert_eq!(half, Rational::new(-1, -2));
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        assert_eq!(Rational::new(3, -6), -half);
+
+        let third = Rational::new(1, 3);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(half * Rational::ZERO, Rational::ZERO);
+        assert_eq!((third * Rational::from(3)).to_integer(), Some(1));
+        assert_eq!(third.to_integer(), None);
+        assert!(third < half);
+        assert_eq!((-half).signum(), Ordering::Less);
+        assert_eq!(half.to_string(), "1/2");
+        assert_eq!(Rational::from(-4).to_string(), "-4");
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(Rational::new(7, 2).floor(), 3);
+        assert_eq!(Rational::new(7, 2).ceil(), 4);
+        assert_eq!(Rational::new(-7, 2).floor(), -4);
+        assert_eq!(Rational::new(-7, 2).ceil(), -3);
+        assert_eq!(Rational::from(5).floor(), 5);
+        assert_eq!(Rational::from(5).ceil(), 5);
+    }
+}
@@ -0,0 +1,63 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// An error found while parsing puzzle input, along with where it happened.
+///
+/// `line` and `column` are 1-based and count characters, as editors do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<E> {
+    pub line: usize,
+    pub column: usize,
+    pub kind: E,
+}
+
+impl<E> ParseError<E> {
+    pub fn new(line: usize, column: usize, kind: E) -> Self {
+        Self { line, column, kind }
+    }
+
+    /// Builds an error located at the start of `part`, which must be a subslice of `text`
+    /// (such as a line or word obtained by splitting it).
+    pub fn at(text: &str, part: &str, kind: E) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|&offset| offset <= text.len())
+            .expect("`part` was not a subslice of `text`");
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+
+    /// Builds an error located at the end of `text`, for input that stopped too early.
+    pub fn at_end(text: &str, kind: E) -> Self {
+        Self::at(text, &text[text.len()..], kind)
+    }
+}
+
+impl<E: Display> Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl<E: Error> Error for ParseError<E> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::ParseError;
+
+    #[test]
+    fn test_at() {
+        let text = "12 34\n56 éx 78\n";
+        let word = text.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        assert_eq!(ParseError::at(text, word, ()), ParseError::new(2, 4, ()));
+        assert_eq!(ParseError::at(text, text, ()), ParseError::new(1, 1, ()));
+        assert_eq!(ParseError::at_end(text, ()), ParseError::new(3, 1, ()));
+    }
+}
@@ -0,0 +1,347 @@
+use std::{
+    array,
+    fmt::{self, Display, Write},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{answers::Verdict, runner::RunError};
+
+/// Durations of every run of a single phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    /// Runs `func` on inputs produced by `setup` `runs` times, timing only `func`, and returns
+    /// the output of the last run.
+    pub(crate) fn measure<A, T>(
+        runs: usize,
+        mut setup: impl FnMut() -> A,
+        mut func: impl FnMut(A) -> T,
+    ) -> (T, Self) {
+        let mut samples = Vec::with_capacity(runs);
+        let mut output = None;
+        for _ in 0..runs {
+            let arg = setup();
+            let start = Instant::now();
+            let result = func(arg);
+            samples.push(start.elapsed());
+            output = Some(result);
+        }
+        samples.sort();
+        (output.expect("phase was never run"), Self { samples })
+    }
+
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.samples.len() == 1 {
+            write!(f, "{:.2?}", self.min())
+        } else {
+            write!(
+                f,
+                "min {:.2?}, median {:.2?}, max {:.2?} over {} runs",
+                self.min(),
+                self.median(),
+                self.max(),
+                self.samples.len()
+            )
+        }
+    }
+}
+
+/// The outcome of running one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: String,
+    pub timing: Timing,
+    /// Only present when checking against known answers.
+    pub verdict: Option<Verdict>,
+}
+
+/// The outcome of running a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub parse: Timing,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    /// Returns `false` if any answer contradicted a known one.
+    pub fn passed(&self) -> bool {
+        !self
+            .parts
+            .iter()
+            .any(|part| matches!(part.verdict, Some(Verdict::Fail { .. })))
+    }
+}
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Human-readable lines such as `part 1: 143`.
+    #[default]
+    Text,
+    /// One JSON object per line and part.
+    Json,
+    /// Tab-separated values with a header line.
+    Tsv,
+}
+
+#[derive(Debug)]
+pub struct InvalidFormat(String);
+
+impl FromStr for Format {
+    type Err = InvalidFormat;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "tsv" => Ok(Self::Tsv),
+            _ => Err(InvalidFormat(s.to_owned())),
+        }
+    }
+}
+
+impl Display for InvalidFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid format `{}`, expected `text`, `json` or `tsv`",
+            self.0
+        )
+    }
+}
+
+impl Format {
+    /// The line to print once before any results.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Self::Text | Self::Json => None,
+            Self::Tsv => Some("day\tpart\tanswer\tstatus\ttime_ns\tparse_ns"),
+        }
+    }
+
+    /// Renders the result of one part of `day` as a single line. Timings are always included
+    /// in machine-readable formats, but only shown in text when `show_timing` is set.
+    pub fn render_part(
+        self,
+        day: Option<u32>,
+        parse: &Timing,
+        report: &PartReport,
+        show_timing: bool,
+    ) -> String {
+        let PartReport {
+            part,
+            answer,
+            timing,
+            verdict,
+        } = report;
+        let status = verdict.as_ref().map_or("unchecked", Verdict::status);
+        let day = day.map_or(String::new(), |day| day.to_string());
+        let mut line = String::new();
+
+        match self {
+            Self::Text => {
+                write!(line, "part {part}: {answer}").unwrap();
+                if show_timing {
+                    write!(line, " ({timing})").unwrap();
+                }
+                if let Some(verdict) = verdict {
+                    write!(line, " {verdict}").unwrap();
+                }
+            }
+
+            Self::Json => {
+                let day = if day.is_empty() { "null" } else { &day };
+                write!(
+                    line,
+                    "{{\"day\":{day},\"part\":{part},\"answer\":{},\"status\":\"{status}\"",
+                    json_string(answer)
+                )
+                .unwrap();
+                if let Some(Verdict::Fail { expected }) = verdict {
+                    write!(line, ",\"expected\":{}", json_string(expected)).unwrap();
+                }
+                write!(
+                    line,
+                    ",\"time_ns\":{},\"min_ns\":{},\"max_ns\":{},\"runs\":{},\"parse_ns\":{}}}",
+                    timing.median().as_nanos(),
+                    timing.min().as_nanos(),
+                    timing.max().as_nanos(),
+                    timing.runs(),
+                    parse.median().as_nanos(),
+                )
+                .unwrap();
+            }
+
+            Self::Tsv => {
+                let answer = answer
+                    .replace('\\', "\\\\")
+                    .replace('\t', "\\t")
+                    .replace('\n', "\\n");
+                write!(
+                    line,
+                    "{day}\t{part}\t{answer}\t{status}\t{}\t{}",
+                    timing.median().as_nanos(),
+                    parse.median().as_nanos(),
+                )
+                .unwrap();
+            }
+        }
+
+        line
+    }
+}
+
+/// Renders the outcomes of several days as an aligned table with one row per part, and one row
+/// for each day that could not be run.
+pub fn summary_table(days: &[(u32, Result<Report, RunError>)]) -> String {
+    let mut rows = vec![["day", "part", "answer", "time", "status"].map(String::from)];
+    for (day, result) in days {
+        match result {
+            Ok(report) => rows.extend(report.parts.iter().map(|part| {
+                [
+                    day.to_string(),
+                    part.part.to_string(),
+                    part.answer.clone(),
+                    format!("{:.2?}", part.timing.median()),
+                    part.verdict
+                        .as_ref()
+                        .map_or("-".to_owned(), Verdict::to_string),
+                ]
+            })),
+            Err(err) => rows.push([
+                day.to_string(),
+                "-".to_owned(),
+                err.to_string(),
+                "-".to_owned(),
+                "ERROR".to_owned(),
+            ]),
+        }
+    }
+
+    let widths: [usize; 5] = array::from_fn(|col| {
+        rows.iter()
+            .map(|row| row[col].chars().count())
+            .max()
+            .unwrap_or(0)
+    });
+    let mut table = String::new();
+    for row in rows {
+        let mut line = String::new();
+        for (cell, width) in row.iter().zip(widths) {
+            write!(line, "{cell:<width$}  ").unwrap();
+        }
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", u32::from(c)).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        answers::Verdict,
+        report::{summary_table, Format, PartReport, Report, Timing},
+    };
+
+    fn timing(nanos: u64) -> Timing {
+        Timing {
+            samples: vec![Duration::from_nanos(nanos)],
+        }
+    }
+
+    #[test]
+    fn test_render_part() {
+        let report = PartReport {
+            part: 2,
+            answer: "a \"b\"\n".to_owned(),
+            timing: timing(1500),
+            verdict: Some(Verdict::Fail {
+                expected: "c".to_owned(),
+            }),
+        };
+        let parse = timing(20);
+
+        assert_eq!(
+            Format::Text.render_part(Some(7), &parse, &report, true),
+            "part 2: a \"b\"\n (1.50µs) FAIL (expected c)"
+        );
+        assert_eq!(
+            Format::Json.render_part(Some(7), &parse, &report, false),
+            r#"{"day":7,"part":2,"answer":"a \"b\"\n","status":"fail","expected":"c","time_ns":1500,"min_ns":1500,"max_ns":1500,"runs":1,"parse_ns":20}"#
+        );
+        assert_eq!(
+            Format::Tsv.render_part(None, &parse, &report, false),
+            "\t2\ta \"b\"\\n\tfail\t1500\t20"
+        );
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let report = Report {
+            parse: timing(20),
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    answer: "143".to_owned(),
+                    timing: timing(1500),
+                    verdict: Some(Verdict::Pass),
+                },
+                PartReport {
+                    part: 2,
+                    answer: "123".to_owned(),
+                    timing: timing(25_000),
+                    verdict: None,
+                },
+            ],
+        };
+
+        assert_eq!(
+            summary_table(&[(5, Ok(report))]),
+            "day  part  answer  time     status\n\
+             5    1     143     1.50µs   PASS\n\
+             5    2     123     25.00µs  -\n"
+        );
+    }
+}
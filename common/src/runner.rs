@@ -0,0 +1,499 @@
+use std::{
+    cell::Cell,
+    convert::Infallible,
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    mem,
+    num::NonZeroUsize,
+    path::PathBuf,
+    process,
+    str::FromStr,
+};
+
+use crate::{
+    answers::Answers,
+    inputs::{self, InputArg, InputPattern},
+    parse::ParseError,
+    report::{Format, PartReport, Report, Timing},
+    solution::Solution,
+};
+
+/// Which parts of a puzzle to run. Parts that are not selected are never called, so a slow
+/// part 2 does not hold up part 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Parts {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+#[derive(Debug)]
+pub struct InvalidParts(String);
+
+impl Parts {
+    pub fn includes_part1(self) -> bool {
+        matches!(self, Self::One | Self::Both)
+    }
+
+    pub fn includes_part2(self) -> bool {
+        matches!(self, Self::Two | Self::Both)
+    }
+}
+
+impl FromStr for Parts {
+    type Err = InvalidParts;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            "both" | "all" => Ok(Self::Both),
+            _ => Err(InvalidParts(s.to_owned())),
+        }
+    }
+}
+
+impl Display for InvalidParts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid part `{}`, expected `1`, `2` or `both`", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    #[default]
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Finds the input for `day` as requested by an `--input` argument. Without one, the real
+    /// input is used if it can be found by [`InputPattern`], and stdin otherwise.
+    pub fn locate(arg: Option<InputArg>, day: Option<u32>) -> Result<Self, String> {
+        match (arg, day) {
+            (Some(InputArg::File(file)), _) => Ok(Self::File(file)),
+            (Some(InputArg::Variant(variant)), Some(day)) => Ok(Self::File(
+                InputPattern::from_env().path(day, Some(&variant)),
+            )),
+            (Some(InputArg::Variant(variant)), None) => Err(format!(
+                "cannot find input `{variant}` without knowing the day"
+            )),
+            (None, Some(day)) => {
+                let file = InputPattern::from_env().path(day, None);
+                Ok(if file.is_file() {
+                    Self::File(file)
+                } else {
+                    Self::Stdin
+                })
+            }
+            (None, None) => Ok(Self::Stdin),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, RunError> {
+        let result = match self {
+            Self::File(file) => fs::read_to_string(file),
+            Self::Stdin => {
+                eprintln!("reading from stdin...");
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|err| RunError(format!("{self}: {err}")))
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(file) => write!(f, "{}", file.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Whether and how to measure how long each phase takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Measure {
+    #[default]
+    Off,
+    Time,
+    Bench(NonZeroUsize),
+}
+
+impl Measure {
+    fn runs(self) -> usize {
+        match self {
+            Self::Off | Self::Time => 1,
+            Self::Bench(runs) => runs.get(),
+        }
+    }
+}
+
+/// Why a puzzle could not be run: its input or its known answers could not be read or parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunError(String);
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for RunError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub input: InputSource,
+    pub parts: Parts,
+    pub measure: Measure,
+    /// File holding the known answers to check against, see [`Answers`].
+    pub answers: Option<PathBuf>,
+    pub format: Format,
+    /// The day being run, used to label machine-readable output.
+    pub day: Option<u32>,
+    /// Don't print results as they are computed, leaving that to whoever reads the [`Report`].
+    pub quiet: bool,
+}
+
+/// Usage of the options understood by [`Config::parse_flag`].
+pub const USAGE: &str = "[--part <1|2|both>] [--time] [--bench <N>] [--format <text|json|tsv>]";
+
+impl Config {
+    /// Builds a configuration from the command line of a single day's binary, exiting with a
+    /// usage message if it is invalid.
+    pub fn from_args() -> Self {
+        let mut args = env::args();
+        let program = args.next().unwrap_or_default();
+        Self::parse_args(args).unwrap_or_else(|err| {
+            eprintln!("error: {err}");
+            eprintln!(
+                "usage: {program} [FILE | --input <FILE|VARIANT>] [--answers <FILE>] {USAGE}"
+            );
+            process::exit(2);
+        })
+    }
+
+    fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut input = None;
+        while let Some(arg) = args.next() {
+            if config.parse_flag(&arg, &mut args)? {
+                continue;
+            }
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for `{arg}`"))
+            };
+            let input_arg = match arg.as_str() {
+                "--answers" => {
+                    config.answers = Some(value()?.into());
+                    continue;
+                }
+                "--input" => InputArg::new(value()?),
+                _ if arg.starts_with("--") => return Err(format!("unexpected argument `{arg}`")),
+                _ => InputArg::File(arg.into()),
+            };
+            if input.replace(input_arg).is_some() {
+                return Err("more than one input was given".to_owned());
+            }
+        }
+        config.day = inputs::current_day();
+        config.input = InputSource::locate(input, config.day)?;
+        Ok(config)
+    }
+
+    /// Applies `flag` if it is one of the options shared by every runner, taking its value
+    /// from `args`. Returns whether the flag was recognized.
+    pub fn parse_flag(
+        &mut self,
+        flag: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{flag}`"))
+        };
+        match flag {
+            "--part" => self.parts = value()?.parse().map_err(|err| format!("{err}"))?,
+            "--time" => self.measure = Measure::Time,
+            "--bench" => {
+                let runs = value()?;
+                let runs = runs
+                    .parse()
+                    .map_err(|_| format!("invalid number of runs `{runs}`"))?;
+                self.measure = Measure::Bench(runs);
+            }
+            "--format" => self.format = value()?.parse().map_err(|err| format!("{err}"))?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    pub fn run<I, I1, I2, R1, R2>(
+        &self,
+        parse: impl Fn(String) -> I,
+        part1: impl Fn(&I1) -> R1,
+        part2: impl Fn(&I2) -> R2,
+    ) -> Result<Report, RunError>
+    where
+        I: AsRef<I1> + AsRef<I2>,
+        I1: ?Sized,
+        I2: ?Sized,
+        R1: Display,
+        R2: Display,
+    {
+        self.try_run(
+            |text| Ok::<_, ParseError<Infallible>>(parse(text)),
+            part1,
+            part2,
+        )
+    }
+
+    /// Like [`Config::run`], but with a parser that can fail. Parse errors are returned with
+    /// their location in the input.
+    pub fn try_run<I, E, I1, I2, R1, R2>(
+        &self,
+        parse: impl Fn(String) -> Result<I, ParseError<E>>,
+        part1: impl Fn(&I1) -> R1,
+        part2: impl Fn(&I2) -> R2,
+    ) -> Result<Report, RunError>
+    where
+        I: AsRef<I1> + AsRef<I2>,
+        E: Display,
+        I1: ?Sized,
+        I2: ?Sized,
+        R1: Display,
+        R2: Display,
+    {
+        self.execute(
+            parse,
+            |input: &I| part1(input.as_ref()),
+            |input: &I| part2(input.as_ref()),
+        )
+    }
+
+    /// Like [`Config::try_run`], but with closures that can only be called once, so
+    /// `--bench` with more than one run is rejected.
+    pub fn try_run_once<I, E, I1, I2, R1, R2>(
+        &self,
+        parse: impl FnOnce(String) -> Result<I, ParseError<E>>,
+        part1: impl FnOnce(&I1) -> R1,
+        part2: impl FnOnce(&I2) -> R2,
+    ) -> Result<Report, RunError>
+    where
+        I: AsRef<I1> + AsRef<I2>,
+        E: Display,
+        I1: ?Sized,
+        I2: ?Sized,
+        R1: Display,
+        R2: Display,
+    {
+        if self.measure.runs() > 1 {
+            return Err(RunError(
+                "`--bench` needs a day implementing `Solution` to run it repeatedly".to_owned(),
+            ));
+        }
+        let (parse, part1, part2) = (
+            Cell::new(Some(parse)),
+            Cell::new(Some(part1)),
+            Cell::new(Some(part2)),
+        );
+        self.execute(
+            |text| parse.take().expect("parsing runs once")(text),
+            |input: &I| part1.take().expect("part 1 runs once")(input.as_ref()),
+            |input: &I| part2.take().expect("part 2 runs once")(input.as_ref()),
+        )
+    }
+
+    /// Runs the day implemented by `S`.
+    pub fn run_solution<S: Solution>(&self) -> Result<Report, RunError> {
+        self.execute(|text| S::parse(&text), S::part1, S::part2)
+    }
+
+    fn execute<I, E, R1, R2>(
+        &self,
+        parse: impl Fn(String) -> Result<I, ParseError<E>>,
+        part1: impl Fn(&I) -> R1,
+        part2: impl Fn(&I) -> R2,
+    ) -> Result<Report, RunError>
+    where
+        E: Display,
+        R1: Display,
+        R2: Display,
+    {
+        let runs = self.measure.runs();
+        let answers = self.load_answers()?;
+        let mut text = self.input.read()?;
+
+        // only the last run takes the text itself, so it isn't copied unless benchmarking
+        let mut runs_left = runs;
+        let next_text = || {
+            runs_left -= 1;
+            if runs_left == 0 {
+                mem::take(&mut text)
+            } else {
+                text.clone()
+            }
+        };
+        let (input, parse_timing) = Timing::measure(runs, next_text, &parse);
+        let input = input.map_err(|err| RunError(format!("{}:{err}", self.input)))?;
+        if !self.quiet && self.format == Format::Text && self.measure != Measure::Off {
+            println!("parse: {parse_timing}");
+        }
+
+        let mut parts = vec![];
+        let mut report_part = |part, (answer, timing): (String, Timing)| {
+            let report = PartReport {
+                part,
+                verdict: answers.as_ref().map(|answers| answers.check(part, &answer)),
+                answer,
+                timing,
+            };
+            if !self.quiet {
+                let show_timing = self.measure != Measure::Off;
+                let line = self
+                    .format
+                    .render_part(self.day, &parse_timing, &report, show_timing);
+                println!("{line}");
+            }
+            parts.push(report);
+        };
+        if self.parts.includes_part1() {
+            let measured = Timing::measure(runs, || (), |()| part1(&input).to_string());
+            report_part(1, measured);
+        }
+        if self.parts.includes_part2() {
+            let measured = Timing::measure(runs, || (), |()| part2(&input).to_string());
+            report_part(2, measured);
+        }
+
+        Ok(Report {
+            parse: parse_timing,
+            parts,
+        })
+    }
+
+    /// Reads the known answers, if any were requested. A missing file counts as having no
+    /// known answers.
+    fn load_answers(&self) -> Result<Option<Answers>, RunError> {
+        let Some(path) = &self.answers else {
+            return Ok(None);
+        };
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(Some(Answers::default()))
+            }
+            Err(err) => return Err(RunError(format!("{}: {err}", path.display()))),
+        };
+        text.parse()
+            .map(Some)
+            .map_err(|err| RunError(format!("{}:{err}", path.display())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, num::NonZeroUsize, path::PathBuf};
+
+    use crate::{
+        parse::ParseError,
+        runner::{Config, InputSource, Measure},
+    };
+
+    /// Writes `text` to a file unique to `name` in the temporary directory.
+    fn temp_file(name: &str, text: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("common-runner-{}-{name}", std::process::id()));
+        fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_run_once() {
+        let config = Config {
+            input: InputSource::File(temp_file("run-once", "1 2 3")),
+            quiet: true,
+            ..Default::default()
+        };
+        // the second part moves out of its capture, so it can only be called once
+        let separator = String::from("+");
+        let report = config
+            .try_run_once(
+                |text| {
+                    text.split(' ')
+                        .map(|word| word.parse::<u32>())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| ParseError::new(1, 1, "not a number"))
+                },
+                |numbers: &[u32]| numbers.iter().sum::<u32>(),
+                move |numbers: &[u32]| {
+                    let mut text = separator;
+                    text.extend(numbers.iter().map(u32::to_string));
+                    text
+                },
+            )
+            .unwrap();
+        let answers: Vec<_> = report
+            .parts
+            .iter()
+            .map(|part| part.answer.as_str())
+            .collect();
+        assert_eq!(answers, ["6", "+123"]);
+
+        let bench = Config {
+            measure: Measure::Bench(NonZeroUsize::new(2).unwrap()),
+            ..config
+        };
+        let result = bench.try_run_once(
+            Ok::<_, ParseError<&str>>,
+            |text: &str| text.len(),
+            |text: &str| text.len(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_unreadable_answers() {
+        let input = temp_file("answers-input", "text");
+        let config = Config {
+            input: InputSource::File(input.clone()),
+            answers: Some(input.clone()),
+            quiet: true,
+            ..Default::default()
+        };
+        // the input is no answers file, but a missing one only means no known answers
+        assert!(config.run(|text| text, str::len, str::len).is_err());
+        let missing = Config {
+            answers: Some(input.with_extension("missing")),
+            ..config.clone()
+        };
+        assert!(missing.run(|text| text, str::len, str::len).is_ok());
+
+        let directory = Config {
+            answers: Some(env::temp_dir()),
+            ..config
+        };
+        let err = directory.run(|text| text, str::len, str::len).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(&format!("{}: ", env::temp_dir().display())));
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let file = env::temp_dir().join("common-runner-no-such-input.txt");
+        let config = Config {
+            input: InputSource::File(file.clone()),
+            quiet: true,
+            ..Default::default()
+        };
+        let err = config.run(|text| text, str::len, str::len).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(&format!("{}: ", file.display())));
+    }
+}
@@ -0,0 +1,352 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{array2::Array2, math::Pos};
+
+/// The outcome of a search: the distance from the nearest start to every reached state, and
+/// the state each one was reached from on a shortest path.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, S>,
+}
+
+impl<S, C> Default for Search<S, C> {
+    fn default() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// The state before `state` on its shortest path, `None` for starts and unreached states.
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        self.predecessors.get(state)
+    }
+
+    pub fn predecessors(&self) -> &HashMap<S, S> {
+        &self.predecessors
+    }
+
+    pub fn reached(&self, state: &S) -> bool {
+        self.distances.contains_key(state)
+    }
+
+    /// The shortest path from one of the starts to `state`, both ends included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.reached(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessor(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from all of `starts` at once, where every step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::default();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = search.distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let distance = search.distances[&state] + 1;
+        for next in neighbors(&state) {
+            if let Entry::Vacant(entry) = search.distances.entry(next.clone()) {
+                entry.insert(distance);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm from all of `starts` at once. `neighbors` gives the states reachable
+/// from a state along with the cost of getting there, which must not be negative.
+/// `C::default()` is the cost of the empty path.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(starts, neighbors, |_| C::default(), |_| false).0
+}
+
+/// A* search from `start` to the first state satisfying `is_goal`, returning the cost and the
+/// path to it. `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (search, goal) = best_first([start], neighbors, heuristic, is_goal);
+    let goal = goal?;
+    Some((search.distance(&goal)?, search.path_to(&goal)?))
+}
+
+/// Breadth-first search over the 4-connected cells of `grid`, stepping from one cell to a
+/// neighboring one only if `can_step(from, to)` holds.
+pub fn grid_bfs<T>(
+    grid: &Array2<T>,
+    starts: impl IntoIterator<Item = Pos>,
+    can_step: impl Fn(&T, &T) -> bool,
+) -> Search<Pos, usize> {
+    let can_step = &can_step;
+    bfs(starts, |&pos| {
+        grid.neighbors4(pos)
+            .filter(move |(_, to)| can_step(&grid[pos], to))
+            .map(|(neighbor, _)| neighbor)
+    })
+}
+
+/// Dijkstra's algorithm over the 4-connected cells of `grid`, where `cost(from, to)` is the
+/// cost of stepping from one cell to a neighboring one, or `None` if it's not possible.
+pub fn grid_dijkstra<T, C>(
+    grid: &Array2<T>,
+    starts: impl IntoIterator<Item = Pos>,
+    cost: impl Fn(&T, &T) -> Option<C>,
+) -> Search<Pos, C>
+where
+    C: Copy + Ord + Add<Output = C> + Default,
+{
+    let cost = &cost;
+    dijkstra(starts, |&pos| grid_steps(grid, pos, cost))
+}
+
+/// A* search over the 4-connected cells of `grid` from `start` to `goal`, with costs as in
+/// [`grid_dijkstra`].
+pub fn grid_astar<T, C>(
+    grid: &Array2<T>,
+    start: Pos,
+    goal: Pos,
+    cost: impl Fn(&T, &T) -> Option<C>,
+    heuristic: impl FnMut(Pos) -> C,
+) -> Option<(C, Vec<Pos>)>
+where
+    C: Copy + Ord + Add<Output = C> + Default,
+{
+    let cost = &cost;
+    let mut heuristic = heuristic;
+    astar(
+        start,
+        |&pos| grid_steps(grid, pos, cost),
+        |&pos| heuristic(pos),
+        |&pos| pos == goal,
+    )
+}
+
+fn grid_steps<'a, T, C>(
+    grid: &'a Array2<T>,
+    pos: Pos,
+    cost: &'a impl Fn(&T, &T) -> Option<C>,
+) -> impl Iterator<Item = (Pos, C)> + 'a {
+    grid.neighbors4(pos)
+        .filter_map(move |(neighbor, to)| Some((neighbor, cost(&grid[pos], to)?)))
+}
+
+/// Searches in order of cost plus `heuristic`, stopping at the first state that `is_goal`
+/// accepts.
+fn best_first<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Search<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::default();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = search.distances.entry(start.clone()) {
+            entry.insert(C::default());
+            queue.push(Queued {
+                priority: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            });
+        }
+    }
+
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if search.distances[&state] < cost {
+            // already reached more cheaply
+            continue;
+        }
+        if is_goal(&state) {
+            return (search, Some(state));
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            match search.distances.entry(next.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(next_cost);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(next_cost);
+                }
+            }
+            search.predecessors.insert(next.clone(), state.clone());
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+    (search, None)
+}
+
+/// A state waiting in the queue of [`best_first`], ordered so that the lowest priority is
+/// popped first.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array2::{Array2, UnevenRows},
+        math::Pos,
+        search::{astar, bfs, dijkstra, grid_astar, grid_bfs, grid_dijkstra},
+    };
+
+    fn maze() -> Array2<char> {
+        let text = "\
+S.#.
+..#.
+.#..
+...E";
+        Array2::parse_grid(text, Ok::<_, UnevenRows>).unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([1], |&n: &u32| {
+            [n + 3, n * 2].into_iter().filter(|&m| m <= 20)
+        });
+        assert_eq!(search.distance(&17), Some(4));
+        assert_eq!(search.path_to(&17), Some(vec![1, 4, 7, 14, 17]));
+        assert_eq!(search.distance(&0), None);
+        assert_eq!(search.predecessor(&1), None);
+    }
+
+    #[test]
+    fn test_grid_bfs() {
+        let grid = maze();
+        let search = grid_bfs(&grid, [Pos::new(0, 0)], |_, &to| to != '#');
+        assert_eq!(search.distance(&Pos::new(3, 3)), Some(6));
+        assert_eq!(search.distance(&Pos::new(3, 0)), Some(9));
+        assert!(!search.reached(&Pos::new(2, 0)));
+
+        let path = search.path_to(&Pos::new(3, 3)).unwrap();
+        assert_eq!(path.len(), 7);
+        assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = |&node: &char| -> Vec<(char, u32)> {
+            match node {
+                'a' => vec![('b', 7), ('c', 2)],
+                'c' => vec![('b', 3), ('d', 10)],
+                'b' => vec![('d', 1)],
+                _ => vec![],
+            }
+        };
+        let search = dijkstra(['a'], edges);
+        assert_eq!(search.distance(&'b'), Some(5));
+        assert_eq!(search.distance(&'d'), Some(6));
+        assert_eq!(search.path_to(&'d'), Some(vec!['a', 'c', 'b', 'd']));
+
+        let found = astar('a', edges, |_| 0, |&node| node == 'd');
+        assert_eq!(found, Some((6, vec!['a', 'c', 'b', 'd'])));
+        assert_eq!(astar('b', edges, |_| 0, |&node| node == 'a'), None);
+    }
+
+    #[test]
+    fn test_grid_weighted() {
+        let grid = maze();
+        let cost = |_: &char, &to: &char| match to {
+            '#' => None,
+            '.' => Some(1),
+            _ => Some(5),
+        };
+        let (start, goal) = (Pos::new(0, 0), Pos::new(3, 3));
+        let search = grid_dijkstra(&grid, [start], cost);
+        assert_eq!(search.distance(&goal), Some(10));
+        assert_eq!(search.distance(&start), Some(0));
+
+        let heuristic = |pos: Pos| pos.manhattan(goal);
+        let (total, path) = grid_astar(&grid, start, goal, cost, heuristic).unwrap();
+        assert_eq!(total, 10);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&goal));
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+
+/// A day's puzzle: how to parse its input and how to solve both of its parts.
+///
+/// Implementors are usually unit structs named after the day, run with
+/// [`run_solution`](crate::run_solution) or [`Config::run_solution`](crate::runner::Config::run_solution).
+pub trait Solution {
+    type Input;
+    /// What can go wrong while parsing, [`Infallible`](std::convert::Infallible) if nothing can.
+    type Error: Display;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
@@ -0,0 +1,220 @@
+use std::{collections::BTreeMap, ops::Index};
+
+use crate::{array2::Array2, math::Pos};
+
+/// The smallest rectangle containing a set of positions, with both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y) as usize + 1
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x) && (self.min.y..=self.max.y).contains(&pos.y)
+    }
+
+    fn including(self, pos: Pos) -> Self {
+        Self {
+            min: Pos::new(self.min.x.min(pos.x), self.min.y.min(pos.y)),
+            max: Pos::new(self.max.x.max(pos.x), self.max.y.max(pos.y)),
+        }
+    }
+
+    fn on_edge(&self, pos: Pos) -> bool {
+        pos.x == self.min.x || pos.x == self.max.x || pos.y == self.min.y || pos.y == self.max.y
+    }
+}
+
+/// A grid that only stores the cells that were set, at any position, including negative ones.
+/// Cells are iterated over in reading order.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    /// Cells keyed by `(y, x)` to keep them in reading order.
+    cells: BTreeMap<(isize, isize), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: BTreeMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes the cells of `array` for which `keep` holds, at the same positions.
+    pub fn from_array(array: Array2<T>, mut keep: impl FnMut(&T) -> bool) -> Self {
+        array
+            .into_indexed_iter()
+            .filter(|(_, cell)| keep(cell))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The bounding box of the cells, `None` if there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&(pos.y, pos.x))
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&(pos.y, pos.x))
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&(pos.y, pos.x))
+    }
+
+    /// Sets the cell at `pos`, returning its previous value.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(pos),
+            None => Bounds { min: pos, max: pos },
+        });
+        self.cells.insert((pos.y, pos.x), value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        let value = self.cells.remove(&(pos.y, pos.x))?;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(pos)) {
+            self.bounds = self.positions().fold(None, |bounds, pos| match bounds {
+                Some(bounds) => Some(bounds.including(pos)),
+                None => Some(Bounds { min: pos, max: pos }),
+            });
+        }
+        Some(value)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.cells.keys().map(|&(y, x)| Pos::new(x, y))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells
+            .iter()
+            .map(|(&(y, x), cell)| (Pos::new(x, y), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.cells
+            .iter_mut()
+            .map(|(&(y, x), cell)| (Pos::new(x, y), cell))
+    }
+
+    /// Lays the cells out in an array covering the [bounds](SparseGrid::bounds), so the cell
+    /// at `pos` ends up at `pos - bounds.min`. Missing cells are `None`.
+    pub fn to_array(&self) -> Array2<Option<&T>> {
+        let Some(bounds) = self.bounds else {
+            return Array2::default();
+        };
+        let mut array = Array2::from_element(None, bounds.width(), bounds.height());
+        for (pos, cell) in self.iter() {
+            array[Pos::ORIGIN + (pos - bounds.min)] = Some(cell);
+        }
+        array
+    }
+
+    /// Renders the cells covering the [bounds](SparseGrid::bounds) like
+    /// [`Array2::render`], with `empty` for missing cells.
+    pub fn render(&self, cell: impl Fn(&T) -> char, empty: char) -> String {
+        self.to_array()
+            .render(|value| value.map_or(empty, &cell))
+            .to_string()
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+impl<T> Index<Pos> for SparseGrid<T> {
+    type Output = T;
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("no cell at ({}, {}) in `SparseGrid`", pos.x, pos.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array2::{Array2, UnevenRows},
+        math::Pos,
+        sparse_grid::{Bounds, SparseGrid},
+    };
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = [(Pos::new(2, -1), 'b'), (Pos::new(-1, 1), 'a')]
+            .into_iter()
+            .collect();
+        grid.insert(Pos::new(0, -1), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Pos::new(-1, -1),
+                max: Pos::new(2, 1)
+            })
+        );
+        assert_eq!(grid[Pos::new(2, -1)], 'b');
+        assert_eq!(grid.get(Pos::new(1, 1)), None);
+        assert_eq!(
+            grid.positions().collect::<Vec<_>>(),
+            [Pos::new(0, -1), Pos::new(2, -1), Pos::new(-1, 1)]
+        );
+        assert_eq!(grid.render(|&c| c, '.'), ".c.b\n....\na...");
+
+        assert_eq!(grid.remove(Pos::new(-1, 1)), Some('a'));
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Pos::new(0, -1),
+                max: Pos::new(2, -1)
+            })
+        );
+        grid.remove(Pos::new(0, -1));
+        grid.remove(Pos::new(2, -1));
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render(|&c| c, '.'), "");
+    }
+
+    #[test]
+    fn test_array_conversion() {
+        let array = Array2::parse_grid("#..\n..#", Ok::<_, UnevenRows>).unwrap();
+        let grid = SparseGrid::from_array(array, |&c| c == '#');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds().map(|bounds| bounds.width()), Some(3));
+        assert_eq!(grid.to_array().raw_data()[..3], [Some(&'#'), None, None]);
+    }
+}
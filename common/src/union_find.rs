@@ -0,0 +1,224 @@
+use std::mem;
+
+use crate::{
+    array2::Array2,
+    math::{Pos, Vect},
+};
+
+/// Data that can be combined into one when the sets holding it are joined.
+pub trait Merge {
+    fn merge(&mut self, other: Self);
+}
+
+impl Merge for () {
+    fn merge(&mut self, _: Self) {}
+}
+
+/// Disjoint sets of elements numbered from 0, with path compression and union by rank. Each set
+/// carries a payload of type `T`, which is [merged](Merge) when sets are joined.
+#[derive(Debug, Clone)]
+pub struct UnionFind<T = ()> {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    /// The payload of each set, stored at its root.
+    payloads: Vec<Option<T>>,
+    n_sets: usize,
+}
+
+impl<T> Default for UnionFind<T> {
+    fn default() -> Self {
+        Self {
+            parents: vec![],
+            ranks: vec![],
+            payloads: vec![],
+            n_sets: 0,
+        }
+    }
+}
+
+impl UnionFind {
+    /// Creates `len` elements, each in a set of its own.
+    pub fn with_len(len: usize) -> Self {
+        let mut sets = Self::default();
+        for _ in 0..len {
+            sets.push(());
+        }
+        sets
+    }
+}
+
+impl<T> UnionFind<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a new element in a set of its own, returning the element.
+    pub fn push(&mut self, payload: T) -> usize {
+        let element = self.parents.len();
+        self.parents.push(element);
+        self.ranks.push(0);
+        self.payloads.push(Some(payload));
+        self.n_sets += 1;
+        element
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of disjoint sets.
+    pub fn n_sets(&self) -> usize {
+        self.n_sets
+    }
+
+    /// The representative of the set holding `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut element = element;
+        while self.parents[element] != root {
+            element = mem::replace(&mut self.parents[element], root);
+        }
+        root
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Joins the sets holding `a` and `b` and returns the representative of the joined set.
+    /// The payload of the set absorbing the other is merged with the other's payload.
+    pub fn union(&mut self, a: usize, b: usize) -> usize
+    where
+        T: Merge,
+    {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return a;
+        }
+
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.parents[child] = root;
+        self.n_sets -= 1;
+
+        let absorbed = self.payloads[child].take().unwrap();
+        self.payloads[root].as_mut().unwrap().merge(absorbed);
+        root
+    }
+
+    /// The payload of the set holding `element`.
+    pub fn payload(&mut self, element: usize) -> &T {
+        let root = self.find(element);
+        self.payloads[root].as_ref().unwrap()
+    }
+
+    pub fn payload_mut(&mut self, element: usize) -> &mut T {
+        let root = self.find(element);
+        self.payloads[root].as_mut().unwrap()
+    }
+
+    /// Iterates over the representative and payload of each set.
+    pub fn sets(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.payloads
+            .iter()
+            .enumerate()
+            .filter_map(|(root, payload)| Some((root, payload.as_ref()?)))
+    }
+}
+
+/// Labels the connected regions of `grid`, where two orthogonally adjacent cells are in the
+/// same region if `same_region` holds for them. Labels are numbered from 0 in the reading order
+/// of each region's first cell, and the number of regions is returned along with them.
+pub fn label_regions<T>(
+    grid: &Array2<T>,
+    same_region: impl Fn(&T, &T) -> bool,
+) -> (Array2<usize>, usize) {
+    let mut sets = UnionFind::with_len(grid.width() * grid.height());
+    let index = |pos: Pos| pos.y as usize * grid.width() + pos.x as usize;
+    for (pos, cell) in grid.indexed_iter() {
+        for step in [Vect::new(-1, 0), Vect::new(0, -1)] {
+            if grid
+                .get(pos + step)
+                .is_some_and(|neighbor| same_region(neighbor, cell))
+            {
+                sets.union(index(pos), index(pos + step));
+            }
+        }
+    }
+
+    let mut labels = vec![None; sets.len()];
+    let mut n_regions = 0;
+    let grid_labels = Array2::from_fn(grid.width(), grid.height(), |pos| {
+        let root = sets.find(index(pos));
+        *labels[root].get_or_insert_with(|| {
+            n_regions += 1;
+            n_regions - 1
+        })
+    });
+    (grid_labels, n_regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array2::{Array2, UnevenRows},
+        union_find::{label_regions, Merge, UnionFind},
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct Size(u32);
+
+    impl Merge for Size {
+        fn merge(&mut self, other: Self) {
+            self.0 += other.0;
+        }
+    }
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new();
+        for _ in 0..6 {
+            sets.push(Size(1));
+        }
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(1, 3);
+        assert!(sets.same_set(0, 2));
+        assert!(!sets.same_set(0, 4));
+        assert_eq!(sets.n_sets(), 3);
+        assert_eq!(sets.payload(3), &Size(4));
+
+        sets.payload_mut(5).0 += 10;
+        let mut sizes: Vec<u32> = sets.sets().map(|(_, size)| size.0).collect();
+        sizes.sort();
+        assert_eq!(sizes, [1, 4, 11]);
+
+        let root = sets.union(0, 3);
+        assert_eq!(root, sets.find(2));
+        assert_eq!(sets.n_sets(), 3);
+    }
+
+    #[test]
+    fn test_label_regions() {
+        let text = "AAB\nBAB\nBBA";
+        let grid = Array2::parse_grid(text, Ok::<_, UnevenRows>).unwrap();
+        let (labels, n_regions) = label_regions(&grid, |a, b| a == b);
+        assert_eq!(n_regions, 4);
+        assert_eq!(labels.raw_data(), &[0, 0, 1, 2, 0, 1, 2, 2, 3]);
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+use common::{parse::ParseError, Solution};
+
+pub struct Input {
+    left: Vec<i32>,
+    right: Vec<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingNumber,
+    MissingSecondNumber,
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingNumber => write!(f, "missing number"),
+            Self::MissingSecondNumber => write!(f, "missing second number"),
+            Self::InvalidNumber(err) => write!(f, "invalid number: {err}"),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError<ErrorKind>> {
+    let parse_number = |word: &str| {
+        word.parse()
+            .map_err(|err| ParseError::at(input, word, ErrorKind::InvalidNumber(err)))
+    };
+
+    let mut left = vec![];
+    let mut right = vec![];
+    for line in input.lines().filter(|s| !s.is_empty()) {
+        let mut numbers = line.split(' ').filter(|s| !s.is_empty());
+        let first = numbers
+            .next()
+            .ok_or_else(|| ParseError::at(input, line, ErrorKind::MissingNumber))?;
+        left.push(parse_number(first)?);
+        let second = numbers.next().ok_or_else(|| {
+            ParseError::at(input, &line[line.len()..], ErrorKind::MissingSecondNumber)
+        })?;
+        right.push(parse_number(second)?);
+    }
+    Ok(Input { left, right })
+}
+
+pub fn part1(input: &Input) -> u32 {
+    let mut left = input.left.clone();
+    let mut right = input.right.clone();
+    left.sort();
+    right.sort();
+    left.iter().zip(&right).map(|(&l, &r)| l.abs_diff(r)).sum()
+}
+
+pub fn part2(input: &Input) -> i32 {
+    let mut occurences_in_right: HashMap<i32, i32> = HashMap::new();
+    for &n in &input.right {
+        *occurences_in_right.entry(n).or_insert(0) += 1;
+    }
+    let mut score = 0;
+    for &n in &input.left {
+        score += n * occurences_in_right.get(&n).copied().unwrap_or(0);
+    }
+    score
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+    type Error = ErrorKind;
+    type Answer1 = u32;
+    type Answer2 = i32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        parse_input(text)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, Day01, ErrorKind, Input};
+    use common::parse::ParseError;
+
+    const SMALL_INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    common::examples! {
+        Day01;
+        small: SMALL_INPUT => part1 = 11, part2 = 31;
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let Input { left, right } = parse_input(SMALL_INPUT).unwrap();
+        assert_eq!(left, &[3, 4, 2, 1, 3, 3]);
+        assert_eq!(right, &[4, 3, 5, 3, 9, 3]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("3   4\n4   x\n2").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert!(matches!(err.kind, ErrorKind::InvalidNumber(_)));
+
+        let err = parse_input("3   4\n2").err().unwrap();
+        assert_eq!(err, ParseError::new(2, 2, ErrorKind::MissingSecondNumber));
+    }
+}
@@ -0,0 +1,127 @@
+use std::num::ParseIntError;
+
+use common::{parse::ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Input {
+    reports: Vec<Vec<i32>>,
+}
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError<ParseIntError>> {
+    let reports = input
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .map(|n| n.parse().map_err(|err| ParseError::at(input, n, err)))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Input { reports })
+}
+
+pub fn part1(input: &Input) -> usize {
+    input
+        .reports
+        .iter()
+        .filter(|report| report_is_safe(report))
+        .count()
+}
+
+pub fn part2(input: &Input) -> usize {
+    input
+        .reports
+        .iter()
+        .filter(|report| report_is_safe_with_skip(report))
+        .count()
+}
+
+fn report_is_safe(report: &[i32]) -> bool {
+    if report.len() <= 1 {
+        return true;
+    }
+
+    let increasing = report[0] < report[1];
+    for (&a, &b) in report.iter().tuple_windows() {
+        let diff = b - a;
+        let diff_is_good =
+            (increasing && (1..=3).contains(&diff)) || (!increasing && (-3..=-1).contains(&diff));
+        if !diff_is_good {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn report_is_safe_with_skip(report: &[i32]) -> bool {
+    if report_is_safe(report) {
+        return true;
+    }
+
+    for i in 0..report.len() {
+        let report_without_i = report
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, &level)| (idx != i).then_some(level))
+            .collect_vec();
+        if report_is_safe(&report_without_i) {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Input;
+    type Error = ParseIntError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        parse_input(text)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_input, Day02};
+
+    const SMALL_INPUT: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    common::examples! {
+        Day02;
+        small: SMALL_INPUT => part1 = 2, part2 = 4;
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let reports = parse_input(SMALL_INPUT).unwrap().reports;
+        assert_eq!(
+            reports,
+            &[
+                &[7, 6, 4, 2, 1],
+                &[1, 2, 7, 8, 9],
+                &[9, 7, 6, 2, 1],
+                &[1, 3, 2, 4, 5],
+                &[8, 6, 4, 4, 1],
+                &[1, 3, 6, 7, 9],
+            ],
+        )
+    }
+}
@@ -0,0 +1,209 @@
+use std::convert::Infallible;
+
+use common::{parse::ParseError, Solution};
+
+pub fn part1(mut input: &str) -> u32 {
+    let mut sum = 0;
+    let mut parser = MulParser::new();
+    while !input.is_empty() {
+        let ParserStep { finished, tail } = parser.step(input);
+        if let Some((lhs, rhs)) = finished {
+            sum += lhs * rhs;
+        }
+        input = tail;
+    }
+    sum
+}
+
+pub fn part2(mut input: &str) -> u32 {
+    let mut sum = 0;
+    let mut parser = Parser::new();
+    while !input.is_empty() {
+        let ParserStep { finished, tail } = parser.step(input);
+        if let Some((lhs, rhs)) = finished {
+            sum += lhs * rhs;
+        }
+        input = tail;
+    }
+    sum
+}
+
+struct Parser {
+    active: Option<MulParser>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MulParser {
+    MulLeftParen,
+    Lhs,
+    Comma { lhs: u32 },
+    Rhs { lhs: u32 },
+    RightParen { lhs: u32, rhs: u32 },
+}
+
+struct ParserStep<'a> {
+    finished: Option<(u32, u32)>,
+    tail: &'a str,
+}
+
+impl Parser {
+    fn new() -> Self {
+        Self {
+            active: Some(MulParser::new()),
+        }
+    }
+}
+
+impl Parser {
+    fn step<'a>(&mut self, input: &'a str) -> ParserStep<'a> {
+        if let Some(tail) = input.strip_prefix("do()") {
+            self.active = Some(MulParser::MulLeftParen);
+            return ParserStep {
+                finished: None,
+                tail,
+            };
+        }
+        if let Some(tail) = input.strip_prefix("don't()") {
+            self.active = None;
+            return ParserStep {
+                finished: None,
+                tail,
+            };
+        }
+
+        if let Some(parser) = &mut self.active {
+            return parser.step(input);
+        }
+
+        ParserStep {
+            finished: None,
+            tail: skip_first_char(input),
+        }
+    }
+}
+
+impl MulParser {
+    fn new() -> Self {
+        Self::MulLeftParen
+    }
+
+    fn step<'a>(&mut self, input: &'a str) -> ParserStep<'a> {
+        match *self {
+            Self::MulLeftParen => ParserStep {
+                finished: None,
+                tail: self.step_exact(input, "mul(", Self::Lhs).1,
+            },
+
+            Self::Lhs => {
+                let (lhs, tail) = Self::step_number(input);
+                *self = match lhs {
+                    Some(lhs) => Self::Comma { lhs },
+                    None => Self::MulLeftParen,
+                };
+                ParserStep {
+                    finished: None,
+                    tail,
+                }
+            }
+
+            Self::Comma { lhs } => ParserStep {
+                finished: None,
+                tail: self.step_exact(input, ",", Self::Rhs { lhs }).1,
+            },
+
+            Self::Rhs { lhs } => {
+                let (rhs, tail) = Self::step_number(input);
+                *self = match rhs {
+                    Some(rhs) => Self::RightParen { lhs, rhs },
+                    None => Self::MulLeftParen,
+                };
+                ParserStep {
+                    finished: None,
+                    tail,
+                }
+            }
+
+            Self::RightParen { lhs, rhs } => {
+                let (success, tail) = self.step_exact(input, ")", Self::MulLeftParen);
+                ParserStep {
+                    finished: success.then_some((lhs, rhs)),
+                    tail,
+                }
+            }
+        }
+    }
+
+    fn step_exact<'a>(
+        &mut self,
+        input: &'a str,
+        prefix: &str,
+        next_state: MulParser,
+    ) -> (bool, &'a str) {
+        match input.strip_prefix(prefix) {
+            Some(tail) => {
+                *self = next_state;
+                (true, tail)
+            }
+            None => {
+                *self = Self::MulLeftParen;
+                (false, skip_first_char(input))
+            }
+        }
+    }
+
+    fn step_number(input: &str) -> (Option<u32>, &str) {
+        let mut number: Option<u32> = None;
+        let mut chars = input.chars();
+        for _ in 0..3 {
+            let Some(digit) = chars.clone().next().and_then(|c| c.to_digit(10)) else {
+                break;
+            };
+            chars.next();
+            number = number.map(|number| number * 10 + digit).or(Some(digit));
+        }
+        if number.is_none() {
+            chars.next();
+        }
+        (number, chars.as_str())
+    }
+}
+
+fn skip_first_char(input: &str) -> &str {
+    let mut chars = input.chars();
+    chars.next();
+    chars.as_str()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+    type Error = Infallible;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        Ok(text.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+
+    common::examples! {
+        Day03;
+        small1: "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"
+            => part1 = 161;
+        small2: "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"
+            => part2 = 48;
+    }
+}
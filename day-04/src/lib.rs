@@ -0,0 +1,123 @@
+use std::str::FromStr;
+
+use common::{
+    array2::{Array2, UnevenRows},
+    math::Pos,
+    parse::ParseError,
+    Solution,
+};
+
+pub mod part1;
+pub mod part2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Letter {
+    X,
+    M,
+    A,
+    S,
+}
+
+pub struct Input {
+    letters: Array2<Option<Letter>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError<UnevenRows>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let letters = Array2::parse_grid(s, |c| Ok(c.try_into().ok()))?;
+        Ok(Self { letters })
+    }
+}
+
+impl Input {
+    fn get(&self, pos: Pos) -> Option<Letter> {
+        self.letters.get(pos).copied().flatten()
+    }
+
+    fn indexed_letters(&self) -> impl Iterator<Item = (Pos, Letter)> + '_ {
+        self.letters
+            .indexed_iter()
+            .filter_map(|(pos, l)| Some((pos, (*l)?)))
+    }
+}
+
+impl TryFrom<char> for Letter {
+    type Error = ();
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Self::X),
+            'M' => Ok(Self::M),
+            'A' => Ok(Self::A),
+            'S' => Ok(Self::S),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+    type Error = UnevenRows;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        text.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{array2::UnevenRows, parse::ParseError};
+
+    use crate::{Day04, Input, Letter};
+
+    const SMALL_INPUT: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    common::examples! {
+        Day04;
+        small: SMALL_INPUT => part1 = 18, part2 = 9;
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let tiny_input: &str = "XMAS
+NO T
+MIXE";
+        let input: Input = tiny_input.parse().unwrap();
+
+        let x = Some(Letter::X);
+        let m = Some(Letter::M);
+        let a = Some(Letter::A);
+        let s = Some(Letter::S);
+        let n = None;
+
+        let expected: &[&[Option<Letter>]] = &[&[x, m, a, s], &[n; 4], &[m, n, x, n]];
+
+        assert_eq!(input.letters.rows().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            "XMAS\nNO THING".parse::<Input>().err(),
+            Some(ParseError::new(2, 1, UnevenRows))
+        );
+    }
+}
@@ -1,35 +1,9 @@
-use std::iter;
+use common::math::{Dir8, Pos, Vect};
 
 use crate::{Input, Letter};
 
-#[derive(Debug, Clone, Copy)]
-struct Direction {
-    dx: isize,
-    dy: isize,
-}
-
-impl Direction {
-    fn cardinal_and_diagonal() -> [Self; 8] {
-        [
-            Self { dx: -1, dy: -1 },
-            Self { dx: -1, dy: 0 },
-            Self { dx: -1, dy: 1 },
-            Self { dx: 0, dy: -1 },
-            Self { dx: 0, dy: 1 },
-            Self { dx: 1, dy: -1 },
-            Self { dx: 1, dy: 0 },
-            Self { dx: 1, dy: 1 },
-        ]
-    }
-
-    fn ray_from(self, mut x: isize, mut y: isize) -> impl Iterator<Item = (isize, isize)> {
-        iter::from_fn(move || {
-            let result = (x, y);
-            x += self.dx;
-            y += self.dy;
-            Some(result)
-        })
-    }
+fn ray_from(pos: Pos, direction: Vect) -> impl Iterator<Item = Pos> {
+    (0..).map(move |t| pos + t * direction)
 }
 
 const XMAS: [Letter; 4] = [Letter::X, Letter::M, Letter::A, Letter::S];
@@ -37,16 +11,15 @@ const XMAS: [Letter; 4] = [Letter::X, Letter::M, Letter::A, Letter::S];
 pub fn part1(input: &Input) -> usize {
     let mut matches = 0;
 
-    for (x, y, l) in input.indexed_letters() {
+    for (pos, l) in input.indexed_letters() {
         if l != Letter::X {
             continue;
         }
 
-        for direction in Direction::cardinal_and_diagonal() {
-            if direction
-                .ray_from(x, y)
+        for direction in Dir8::ALL {
+            if ray_from(pos, direction.vect())
                 .zip(XMAS)
-                .all(|((x, y), letter)| input.get(x, y) == Some(letter))
+                .all(|(pos, letter)| input.get(pos) == Some(letter))
             {
                 matches += 1;
             }
@@ -55,14 +28,3 @@ pub fn part1(input: &Input) -> usize {
 
     matches
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::{part1, tests::SMALL_INPUT, Input};
-
-    #[test]
-    fn test_part1() {
-        let input: Input = SMALL_INPUT.parse().unwrap();
-        assert_eq!(part1::part1(&input), 18);
-    }
-}
@@ -1,3 +1,5 @@
+use common::math::{Dir8, Pos};
+
 use crate::{Input, Letter};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -17,9 +19,15 @@ impl TryFrom<Letter> for LetterMS {
     }
 }
 
-fn check_cross(input: &Input, x: isize, y: isize) -> bool {
-    check_opposites(input.get(x - 1, y - 1), input.get(x + 1, y + 1))
-        && check_opposites(input.get(x - 1, y + 1), input.get(x + 1, y - 1))
+fn check_cross(input: &Input, pos: Pos) -> bool {
+    [Dir8::DownRight, Dir8::DownLeft]
+        .into_iter()
+        .all(|diagonal| {
+            check_opposites(
+                input.get(pos + diagonal.opposite().vect()),
+                input.get(pos + diagonal.vect()),
+            )
+        })
 }
 
 fn check_opposites(a: Option<Letter>, b: Option<Letter>) -> bool {
@@ -31,24 +39,13 @@ fn check_opposites(a: Option<Letter>, b: Option<Letter>) -> bool {
 
 pub fn part2(input: &Input) -> usize {
     let mut matches = 0;
-    for (x, y, l) in input.indexed_letters() {
+    for (pos, l) in input.indexed_letters() {
         if l != Letter::A {
             continue;
         }
-        if check_cross(input, x, y) {
+        if check_cross(input, pos) {
             matches += 1;
         }
     }
     matches
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::{part2::part2, tests::SMALL_INPUT};
-
-    #[test]
-    fn test_part2() {
-        let input = SMALL_INPUT.parse().unwrap();
-        assert_eq!(part2(&input), 9);
-    }
-}
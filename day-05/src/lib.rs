@@ -0,0 +1,142 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use common::{parse::ParseError, Solution};
+use data::{Page, PageMap, PageSet};
+
+mod data;
+pub mod part1;
+pub mod part2;
+
+pub struct Input {
+    forward_adjacency: PageMap<PageSet>,
+    updates: Vec<Vec<Page>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingSucceedingPage,
+    InvalidPrecedingPage,
+    InvalidSucceedingPage,
+    InvalidUpdatePage,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSucceedingPage => {
+                write!(f, "missing succeeding page number in ordering rule")
+            }
+            Self::InvalidPrecedingPage => {
+                write!(f, "invalid preceding page number in ordering rule")
+            }
+            Self::InvalidSucceedingPage => {
+                write!(f, "invalid succeeding page number in ordering rule")
+            }
+            Self::InvalidUpdatePage => write!(f, "invalid page number in update"),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError<ErrorKind>;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let parse_page = |word: &str, kind| -> Result<Page, _> {
+            word.parse().map_err(|()| ParseError::at(text, word, kind))
+        };
+
+        let mut lines = text.lines();
+
+        let mut forward_adjacency = PageMap::new();
+        for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+            let mut numbers = line.split('|');
+            let before = parse_page(
+                numbers.next().unwrap_or(line),
+                ErrorKind::InvalidPrecedingPage,
+            )?;
+            let after = numbers.next().ok_or_else(|| {
+                ParseError::at(text, &line[line.len()..], ErrorKind::MissingSucceedingPage)
+            })?;
+            let after = parse_page(after, ErrorKind::InvalidSucceedingPage)?;
+            forward_adjacency
+                .get_or_insert(before, PageSet::new())
+                .insert(after);
+        }
+
+        let update_section = lines;
+        let updates = update_section
+            .map(|line| {
+                line.split(',')
+                    .map(|word| parse_page(word, ErrorKind::InvalidUpdatePage))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            forward_adjacency,
+            updates,
+        })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Input;
+    type Error = ErrorKind;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        text.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1::part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2::part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+
+    const SMALL_INPUT: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    common::examples! {
+        Day05;
+        small: SMALL_INPUT => part1 = 143, part2 = 123;
+    }
+}
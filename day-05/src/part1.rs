@@ -21,14 +21,3 @@ pub fn part1(input: &Input) -> u32 {
         .map(|update| update[update.len() / 2].to_u32())
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::{part1::part1, tests::SMALL_INPUT, Input};
-
-    #[test]
-    fn test_part1() {
-        let input: Input = SMALL_INPUT.parse().unwrap();
-        assert_eq!(part1(&input), 143);
-    }
-}
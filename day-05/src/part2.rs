@@ -28,14 +28,3 @@ pub fn part2(input: &Input) -> u32 {
         .map(|(_, fixed)| fixed[fixed.len() / 2].to_u32())
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::{part2::part2, tests::SMALL_INPUT, Input};
-
-    #[test]
-    fn test_part2() {
-        let input: Input = SMALL_INPUT.parse().unwrap();
-        assert_eq!(part2(&input), 123);
-    }
-}
@@ -0,0 +1,238 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use common::{
+    array2::{Array2, BitGrid, UnevenRows},
+    math::{Dir4, Pos},
+    parse::ParseError,
+    Solution,
+};
+
+/// Whether each cell holds an obstacle.
+type Board = Array2<bool>;
+
+/// The directions the guard has left each cell in, one bit per [`Dir4::index`].
+type Visits = BitGrid;
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    board: Board,
+    initial_guard_position: Pos,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnevenWidth,
+    MultipleGuards,
+    InvalidCharacter(char),
+    NoGuard,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnevenWidth => write!(f, "width was not constant"),
+            Self::MultipleGuards => write!(f, "found multiple guards"),
+            Self::InvalidCharacter(c) => write!(f, "invalid character '{c}'"),
+            Self::NoGuard => write!(f, "no guard specified"),
+        }
+    }
+}
+
+impl From<UnevenRows> for ErrorKind {
+    fn from(_: UnevenRows) -> Self {
+        Self::UnevenWidth
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError<ErrorKind>;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (board, markers) = Array2::parse_grid_with_markers(
+            text,
+            |c| c == '^',
+            |c| match c {
+                '.' | '^' => Ok(false),
+                '#' => Ok(true),
+                c => Err(ErrorKind::InvalidCharacter(c)),
+            },
+        )?;
+
+        let guards = markers.get(&'^').map_or(&[][..], Vec::as_slice);
+        let guard = match guards {
+            [] => return Err(ParseError::at_end(text, ErrorKind::NoGuard)),
+            [guard] => guard,
+            [_, second, ..] => {
+                let (line, column) = (second.y as usize + 1, second.x as usize + 1);
+                return Err(ParseError::new(line, column, ErrorKind::MultipleGuards));
+            }
+        };
+
+        Ok(Self {
+            board,
+            initial_guard_position: *guard,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Guard {
+    pos: Pos,
+    look_direction: Dir4,
+    n_visited: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Walked,
+    Turned,
+    Loop,
+    Stop,
+}
+
+struct Visit {
+    looped: bool,
+    n_visited: usize,
+}
+
+impl Guard {
+    fn new(pos: Pos, look_direction: Dir4) -> Self {
+        Self {
+            pos,
+            look_direction,
+            n_visited: 0,
+        }
+    }
+
+    fn step(&mut self, board: &Board, visits: &mut Visits) -> Step {
+        let in_front_pos = self.pos + self.look_direction.vect();
+        let Some(&obstacle) = board.get(in_front_pos) else {
+            self.n_visited += 1; // do not mark the last cell on the board as marked
+            return Step::Stop;
+        };
+
+        if obstacle {
+            self.look_direction = self.look_direction.cw();
+            return Step::Turned;
+        }
+
+        if !visits.get(self.pos) {
+            self.n_visited += 1;
+        }
+        if !visits.insert(self.pos, 1 << self.look_direction.index()) {
+            return Step::Loop;
+        }
+        self.pos = in_front_pos;
+        Step::Walked
+    }
+
+    fn visit(&mut self, board: &Board, visits: &mut Visits) -> Visit {
+        let looped = loop {
+            match self.step(board, visits) {
+                Step::Walked | Step::Turned => continue,
+                Step::Loop => break true,
+                Step::Stop => break false,
+            }
+        };
+        Visit {
+            looped,
+            n_visited: self.n_visited,
+        }
+    }
+}
+
+fn new_visits(board: &Board) -> Visits {
+    BitGrid::with_bits(board.width(), board.height(), 4)
+}
+
+pub fn part1(input: &Input) -> usize {
+    Guard::new(input.initial_guard_position, Dir4::Up)
+        .visit(&input.board, &mut new_visits(&input.board))
+        .n_visited
+}
+
+pub fn part2(input: &Input) -> usize {
+    let initial_guard = Guard::new(input.initial_guard_position, Dir4::Up);
+    let mut main_visits = new_visits(&input.board);
+    let mut main_guard = initial_guard;
+    let mut sub_board = input.board.clone();
+    let mut sub_visits = new_visits(&input.board);
+    let mut obstructions = BitGrid::new(input.board.width(), input.board.height());
+    loop {
+        match main_guard.step(&input.board, &mut main_visits) {
+            Step::Loop | Step::Stop => break,
+            Step::Turned => {}
+            Step::Walked => {
+                if main_guard.pos == initial_guard.pos {
+                    continue;
+                }
+                sub_board[main_guard.pos] = true;
+                sub_visits.clear();
+                let mut sub_guard = initial_guard;
+                if sub_guard.visit(&sub_board, &mut sub_visits).looped {
+                    obstructions.insert(main_guard.pos, 1);
+                }
+                sub_board[main_guard.pos] = false;
+            }
+        }
+    }
+    obstructions.count_cells()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Input;
+    type Error = ErrorKind;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        text.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::{Day06, ErrorKind, Input};
+
+    const SMALL_INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    common::examples! {
+        Day06;
+        small: SMALL_INPUT => part1 = 41, part2 = 6;
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "..#\n.^.\n.x.".parse::<Input>().err().unwrap();
+        assert_eq!(err, ParseError::new(3, 2, ErrorKind::InvalidCharacter('x')));
+
+        let err = "..#\n.^\n...".parse::<Input>().err().unwrap();
+        assert_eq!(err, ParseError::new(2, 1, ErrorKind::UnevenWidth));
+
+        let err = ".^.\n..^".parse::<Input>().err().unwrap();
+        assert_eq!(err, ParseError::new(2, 3, ErrorKind::MultipleGuards));
+    }
+}
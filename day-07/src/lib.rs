@@ -0,0 +1,172 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use common::{math::strip_digit_suffix, parse::ParseError, Solution};
+
+#[derive(Debug)]
+struct EquationTest {
+    terms: Vec<u64>,
+    result: u64,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    equations: Vec<EquationTest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    MissingTerms,
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTerms => write!(f, "invalid equation input: missing `: ` before terms"),
+            Self::InvalidNumber(err) => write!(f, "invalid number: {err}"),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError<ErrorKind>;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let parse_number = |word: &str| {
+            word.parse()
+                .map_err(|err| ParseError::at(text, word, ErrorKind::InvalidNumber(err)))
+        };
+
+        let equations = text
+            .lines()
+            .map(|line| {
+                let (result, terms) = line.split_once(": ").ok_or_else(|| {
+                    ParseError::at(text, &line[line.len()..], ErrorKind::MissingTerms)
+                })?;
+
+                let result = parse_number(result)?;
+                let terms = terms
+                    .split(' ')
+                    .map(parse_number)
+                    .collect::<Result<_, _>>()?;
+
+                Ok(EquationTest { result, terms })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { equations })
+    }
+}
+
+fn is_valid_equation1(terms: &[u64], result: u64) -> bool {
+    if terms.len() == 1 {
+        return terms[0] == result;
+    }
+
+    let last = *terms.last().expect("empty equation");
+
+    if last > result {
+        return false;
+    }
+
+    if is_valid_equation1(&terms[..(terms.len() - 1)], result - last) {
+        return true;
+    }
+
+    if result.is_multiple_of(last) && is_valid_equation1(&terms[..(terms.len() - 1)], result / last)
+    {
+        return true;
+    }
+
+    false
+}
+
+fn is_valid_equation2(terms: &[u64], result: u64) -> bool {
+    if terms.len() == 1 {
+        return terms[0] == result;
+    }
+
+    let last = *terms.last().expect("empty equation");
+
+    if last > result {
+        return false;
+    }
+
+    if is_valid_equation2(&terms[..(terms.len() - 1)], result - last) {
+        return true;
+    }
+
+    if result.is_multiple_of(last) && is_valid_equation2(&terms[..(terms.len() - 1)], result / last)
+    {
+        return true;
+    }
+
+    if strip_digit_suffix(result, last)
+        .is_some_and(|prefix| is_valid_equation2(&terms[..(terms.len() - 1)], prefix))
+    {
+        return true;
+    }
+
+    false
+}
+
+pub fn part1(input: &Input) -> u64 {
+    input
+        .equations
+        .iter()
+        .filter(|equation| is_valid_equation1(&equation.terms, equation.result))
+        .map(|equation| equation.result)
+        .sum()
+}
+
+pub fn part2(input: &Input) -> u64 {
+    input
+        .equations
+        .iter()
+        .filter(|equation| is_valid_equation2(&equation.terms, equation.result))
+        .map(|equation| equation.result)
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Input;
+    type Error = ErrorKind;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        text.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+
+    const SMALL_INPUT: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    common::examples! {
+        Day07;
+        small: SMALL_INPUT => part1 = 3749, part2 = 11387;
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use common::{
+    array2::{Array2, UnevenRows},
+    math::{Pos, Vect},
+    parse::ParseError,
+    Solution,
+};
+
+pub struct Input {
+    antennas: HashMap<char, Vec<Pos>>,
+    size: (usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidFrequency(char),
+    UnevenRows(UnevenRows),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFrequency(c) => write!(f, "invalid frequency character '{c}'"),
+            Self::UnevenRows(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<UnevenRows> for ErrorKind {
+    fn from(err: UnevenRows) -> Self {
+        Self::UnevenRows(err)
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError<ErrorKind>;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (map, antennas) = Array2::parse_grid_with_markers(
+            text,
+            |c| c != '.',
+            |c| match c {
+                '.' | '0'..='9' | 'a'..='z' | 'A'..='Z' => Ok(()),
+                c => Err(ErrorKind::InvalidFrequency(c)),
+            },
+        )?;
+        Ok(Self {
+            antennas,
+            size: map.size(),
+        })
+    }
+}
+
+fn frequency_antinodes(antennas: &[Pos], size: (usize, usize), out: &mut HashSet<Pos>) {
+    for (i, &pos_a) in antennas.iter().enumerate() {
+        for &pos_b in &antennas[(i + 1)..] {
+            let diff = pos_a - pos_b;
+
+            let antinode_a = pos_a + diff;
+            if antinode_a.within(size) {
+                out.insert(antinode_a);
+            }
+
+            let antinode_b = pos_b - diff;
+            if antinode_b.within(size) {
+                out.insert(antinode_b);
+            }
+        }
+    }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut antinodes = HashSet::new();
+    for antennas in input.antennas.values() {
+        frequency_antinodes(antennas, input.size, &mut antinodes);
+    }
+    antinodes.len()
+}
+
+fn ray(a: Pos, direction: Vect) -> impl Iterator<Item = Pos> {
+    (0..).map(move |t| a + t * direction)
+}
+
+fn antinodes_for_line2(a: Pos, b: Pos, size: (usize, usize), out: &mut HashSet<Pos>) {
+    let direction = (b - a).reduced();
+    let before = ray(a, -direction).take_while(|pos| pos.within(size));
+    let after = ray(a + direction, direction).take_while(|pos| pos.within(size));
+    out.extend(before.chain(after));
+}
+
+fn antinodes_for_frequency2(antennas: &[Pos], size: (usize, usize), out: &mut HashSet<Pos>) {
+    for (i, &a) in antennas.iter().enumerate() {
+        for &b in &antennas[(i + 1)..] {
+            antinodes_for_line2(a, b, size, out);
+        }
+    }
+}
+
+pub fn part2(input: &Input) -> usize {
+    let mut antinodes = HashSet::new();
+    for antennas in input.antennas.values() {
+        antinodes_for_frequency2(antennas, input.size, &mut antinodes);
+    }
+    antinodes.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Input;
+    type Error = ErrorKind;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        text.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+
+    common::examples! {
+        Day08;
+        dir "examples";
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    fmt::{self, Display},
+    iter,
+};
+
+use common::{parse::ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDigit(char);
+
+impl Display for InvalidDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid digit '{}'", self.0)
+    }
+}
+
+pub fn parse_input(text: &str) -> Result<Vec<u8>, ParseError<InvalidDigit>> {
+    let input = text.trim();
+    if let Some((offset, c)) = input.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(ParseError::at(text, &input[offset..], InvalidDigit(c)));
+    }
+    let mut digits = input.as_bytes().to_vec();
+    for d in digits.iter_mut() {
+        *d -= b'0';
+    }
+    Ok(digits)
+}
+
+pub fn part1(input: &[u8]) -> u64 {
+    let mut disk: Vec<Option<usize>> = input
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, &n)| {
+            if idx % 2 == 0 {
+                iter::repeat_n(Some(idx / 2), n.into())
+            } else {
+                iter::repeat_n(None, n.into())
+            }
+        })
+        .collect();
+
+    let mut i = 0;
+    while i < disk.len() {
+        if disk.last().unwrap().is_none() {
+            disk.pop();
+            continue;
+        }
+        if disk[i].is_none() {
+            disk[i] = disk.pop().unwrap();
+        }
+        i += 1;
+    }
+
+    disk.iter()
+        .enumerate()
+        .map(|(idx, id)| u64::try_from(idx * id.unwrap()).unwrap())
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    start: usize,
+    len: usize,
+}
+
+impl Segment {
+    fn checksum(self, id: usize) -> u64 {
+        u64::try_from((self.start..(self.start + self.len)).sum::<usize>() * id).unwrap()
+    }
+}
+
+pub fn part2(input: &[u8]) -> u64 {
+    let mut start = 0;
+    let mut segments: Vec<Segment> = input
+        .iter()
+        .map(|&len| {
+            let len = len.into();
+            let result = Segment { start, len };
+            start += len;
+            result
+        })
+        .collect();
+
+    let mut checksum = 0;
+
+    for id in (1..=(segments.len() / 2)).rev() {
+        let index = id * 2;
+        let file = segments[index];
+
+        let Some(matching_space) = segments[1..index]
+            .chunks_mut(2)
+            .map(|ns| &mut ns[0])
+            .find(|segment| segment.len >= file.len)
+        else {
+            checksum += file.checksum(id);
+            continue;
+        };
+
+        checksum += Segment {
+            start: matching_space.start,
+            len: file.len,
+        }
+        .checksum(id);
+        matching_space.start += file.len;
+        matching_space.len -= file.len;
+    }
+
+    checksum
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u8>;
+    type Error = InvalidDigit;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        parse_input(text)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09;
+
+    const SMALL_INPUT: &str = "2333133121414131402";
+
+    common::examples! {
+        Day09;
+        small: SMALL_INPUT => part1 = 1928, part2 = 2858;
+    }
+}
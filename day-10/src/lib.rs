@@ -0,0 +1,128 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use common::{
+    array2::{Array2, UnevenRows},
+    math::Pos,
+    parse::ParseError,
+    search::grid_bfs,
+    Solution,
+};
+
+pub struct Map {
+    heights: Array2<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidHeight(char),
+    UnevenRows(UnevenRows),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeight(c) => write!(f, "invalid height '{c}'"),
+            Self::UnevenRows(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<UnevenRows> for ErrorKind {
+    fn from(err: UnevenRows) -> Self {
+        Self::UnevenRows(err)
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError<ErrorKind>;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let heights = Array2::parse_grid(text, |c| match c.to_digit(10) {
+            Some(height) => Ok(height as u8),
+            None => Err(ErrorKind::InvalidHeight(c)),
+        })?;
+        Ok(Self { heights })
+    }
+}
+
+pub fn part1(map: &Map) -> usize {
+    map.heights
+        .indexed_iter()
+        .filter(|&(_, &height)| height == 0)
+        .map(|(trailhead, _)| {
+            let search = grid_bfs(&map.heights, [trailhead], |&from, &to| to == from + 1);
+            search
+                .distances()
+                .keys()
+                .filter(|&&pos| map.heights[pos] == 9)
+                .count()
+        })
+        .sum()
+}
+
+pub fn part2(map: &Map) -> u64 {
+    let mut height_positions: [Vec<Pos>; 10] = Default::default();
+    for (pos, &height) in map.heights.indexed_iter() {
+        height_positions[usize::from(height)].push(pos);
+    }
+
+    let mut scores = Array2::from_element(0, map.heights.width(), map.heights.height());
+    for &pos in &height_positions[0] {
+        scores[pos] = 1;
+    }
+
+    for (height, positions) in height_positions.iter().enumerate().skip(1) {
+        for &pos in positions {
+            scores[pos] = map
+                .heights
+                .neighbors4(pos)
+                .filter(|&(_, &h)| usize::from(h) == height - 1)
+                .map(|(neighbor, _)| scores[neighbor])
+                .sum();
+        }
+    }
+
+    height_positions[9].iter().map(|&pos| scores[pos]).sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+    type Error = ErrorKind;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        text.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+
+    const SMALL_INPUT: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    common::examples! {
+        Day10;
+        small: SMALL_INPUT => part1 = 36, part2 = 81;
+    }
+}
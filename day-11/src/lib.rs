@@ -0,0 +1,87 @@
+use std::{collections::HashMap, num::ParseIntError};
+
+use common::{math::split_digits, parse::ParseError, Solution};
+
+pub fn parse_input(input: &str) -> Result<Vec<u64>, ParseError<ParseIntError>> {
+    input
+        .trim()
+        .split(' ')
+        .map(|num| num.parse().map_err(|err| ParseError::at(input, num, err)))
+        .collect()
+}
+
+pub fn simulate(input: &[u64], steps: u32) -> usize {
+    let mut previous: HashMap<u64, usize> = HashMap::new();
+    let mut input = input.to_vec();
+    input.sort();
+    let mut now: HashMap<u64, usize> = input
+        .chunk_by(|a, b| a == b)
+        .map(|chunk| (chunk[0], chunk.len()))
+        .collect();
+
+    for (number, &occurences) in now.iter() {
+        for _ in 0..occurences {
+            eprint!("{number} ");
+        }
+    }
+    eprintln!();
+
+    for _ in 0..steps {
+        (previous, now) = (now, previous);
+
+        assert_ne!(previous.len(), 0);
+        assert_eq!(now.len(), 0);
+
+        for (number, occurences) in previous.drain() {
+            let (a, b) = step_number(number);
+            *now.entry(a).or_insert(0) += occurences;
+            if let Some(b) = b {
+                *now.entry(b).or_insert(0) += occurences;
+            }
+        }
+    }
+
+    now.values().sum()
+}
+
+fn step_number(number: u64) -> (u64, Option<u64>) {
+    if number == 0 {
+        return (1, None);
+    }
+
+    match split_digits(number) {
+        Some((left, right)) => (left, Some(right)),
+        None => (number * 2024, None),
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Error = ParseIntError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        parse_input(text)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        simulate(input, 25)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        simulate(input, 75)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+
+    common::examples! {
+        Day11;
+        small: "125 17" => part1 = 55312;
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt::{self, Display};
+
+use common::{
+    array2::{Array2, UnevenRows},
+    math::Vect,
+    parse::ParseError,
+    union_find::{Merge, UnionFind},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidPlant(char),
+    UnevenRows(UnevenRows),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPlant(c) => write!(f, "invalid plant '{c}'"),
+            Self::UnevenRows(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<UnevenRows> for ErrorKind {
+    fn from(err: UnevenRows) -> Self {
+        Self::UnevenRows(err)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Array2<u8>, ParseError<ErrorKind>> {
+    Array2::parse_grid(input, |c| match c {
+        'A'..='Z' => Ok(c as u8 - b'A'),
+        _ => Err(ErrorKind::InvalidPlant(c)),
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Plot<P> {
+    inner: P,
+    region: usize,
+}
+
+struct Neighbors<'p, P> {
+    left_plot: Option<&'p P>,
+    above_plot: Option<&'p P>,
+    edge_left: bool,
+    edge_above: bool,
+    edge_below: bool,
+    edge_right: bool,
+}
+
+trait Region: Default + Merge {
+    fn to_number(&self) -> u32;
+}
+
+fn process<R: Region, P: Default + Copy>(
+    plants: &Array2<u8>,
+    mut plot_region: impl FnMut(Neighbors<P>) -> (R, P),
+) -> u32 {
+    let mut regions: UnionFind<R> = UnionFind::new();
+    let mut plots: Array2<Plot<P>> = Array2::from_default(plants.width(), plants.height());
+
+    let (left, above) = (Vect::new(-1, 0), Vect::new(0, -1));
+    for (pos, &plant) in plants.indexed_iter() {
+        let left_plot = plots.get(pos + left);
+        let above_plot = plots.get(pos + above);
+
+        let left_plant = plants.get(pos + left).copied();
+        let above_plant = plants.get(pos + above).copied();
+        let right_plant = plants.get(pos - left).copied();
+        let below_plant = plants.get(pos - above).copied();
+
+        let edge_left = left_plant.is_none_or(|p| p != plant);
+        let edge_above = above_plant.is_none_or(|p| p != plant);
+        let edge_right = right_plant.is_none_or(|p| p != plant);
+        let edge_below = below_plant.is_none_or(|p| p != plant);
+
+        let neighbors = Neighbors {
+            left_plot: left_plot.map(|plot| &plot.inner),
+            above_plot: above_plot.map(|plot| &plot.inner),
+            edge_left,
+            edge_above,
+            edge_right,
+            edge_below,
+        };
+        let (plot_region, plot_data) = plot_region(neighbors);
+
+        let left_region = left_plot.filter(|_| !edge_left).map(|plot| plot.region);
+        let above_region = above_plot.filter(|_| !edge_above).map(|plot| plot.region);
+
+        let region = match (left_region, above_region) {
+            (None, None) => regions.push(R::default()),
+            (Some(region), None) | (None, Some(region)) => region,
+            (Some(left_region), Some(above_region)) => regions.union(left_region, above_region),
+        };
+
+        plots[pos] = Plot {
+            inner: plot_data,
+            region,
+        };
+
+        regions.payload_mut(region).merge(plot_region);
+    }
+
+    regions.sets().map(|(_, region)| region.to_number()).sum()
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Region1 {
+    perimeter: u32,
+    area: u32,
+}
+
+impl Merge for Region1 {
+    fn merge(&mut self, other: Self) {
+        self.perimeter += other.perimeter;
+        self.area += other.area;
+    }
+}
+
+impl Region for Region1 {
+    fn to_number(&self) -> u32 {
+        self.perimeter * self.area
+    }
+}
+
+pub fn part1(plants: &Array2<u8>) -> u32 {
+    process(plants, |neighbors| {
+        let n_edges = [
+            neighbors.edge_left,
+            neighbors.edge_above,
+            neighbors.edge_right,
+            neighbors.edge_below,
+        ]
+        .into_iter()
+        .filter(|edge| *edge)
+        .count();
+        let region = Region1 {
+            perimeter: n_edges as u32,
+            area: 1,
+        };
+        (region, ())
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Region2 {
+    n_edges: u32,
+    area: u32,
+}
+
+impl Merge for Region2 {
+    fn merge(&mut self, other: Self) {
+        self.n_edges += other.n_edges;
+        self.area += other.area;
+    }
+}
+
+impl Region for Region2 {
+    fn to_number(&self) -> u32 {
+        self.area * self.n_edges
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Plot2 {
+    edge_left: bool,
+    edge_right: bool,
+    edge_above: bool,
+    edge_below: bool,
+}
+
+pub fn part2(plants: &Array2<u8>) -> u32 {
+    process(plants, |neighbors| -> (Region2, Plot2) {
+        let mut n_new_edges = 0;
+
+        let left_plot_same_plant = neighbors.left_plot.filter(|_| !neighbors.edge_left);
+
+        if neighbors.edge_above
+            && left_plot_same_plant.is_none_or(|left_plot| !left_plot.edge_above)
+        {
+            n_new_edges += 1;
+        }
+        if neighbors.edge_below
+            && left_plot_same_plant.is_none_or(|left_plot| !left_plot.edge_below)
+        {
+            n_new_edges += 1;
+        }
+
+        let above_plot_same_plant = neighbors.above_plot.filter(|_| !neighbors.edge_above);
+
+        if neighbors.edge_left
+            && above_plot_same_plant.is_none_or(|above_plot| !above_plot.edge_left)
+        {
+            n_new_edges += 1;
+        }
+        if neighbors.edge_right
+            && above_plot_same_plant.is_none_or(|above_plot| !above_plot.edge_right)
+        {
+            n_new_edges += 1;
+        }
+
+        (
+            Region2 {
+                n_edges: n_new_edges,
+                area: 1,
+            },
+            Plot2 {
+                edge_left: neighbors.edge_left,
+                edge_above: neighbors.edge_above,
+                edge_right: neighbors.edge_right,
+                edge_below: neighbors.edge_below,
+            },
+        )
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Array2<u8>;
+    type Error = ErrorKind;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        parse_input(text)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day12;
+
+    common::examples! {
+        Day12;
+        dir "examples";
+    }
+}
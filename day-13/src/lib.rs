@@ -0,0 +1,183 @@
+use std::fmt::{self, Display};
+
+use common::{
+    math::{solve_linear, Pos, Vect},
+    parse::ParseError,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Input {
+    button_a: Vect<i64>,
+    button_b: Vect<i64>,
+    prize: Pos<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidButtonA,
+    InvalidButtonB,
+    InvalidPrize,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidButtonA => write!(f, "expected `Button A: X+<dx>, Y+<dy>`"),
+            Self::InvalidButtonB => write!(f, "expected `Button B: X+<dx>, Y+<dy>`"),
+            Self::InvalidPrize => write!(f, "expected `Prize: X=<x>, Y=<y>`"),
+        }
+    }
+}
+
+pub fn parse_inputs(text: &str) -> Result<Vec<Input>, ParseError<ErrorKind>> {
+    text.split("\n\n")
+        .map(|machine| {
+            parse_one_input(machine).map_err(|(line, kind)| ParseError::at(text, line, kind))
+        })
+        .collect()
+}
+
+/// On failure, returns the offending line (or the end of `text` if a line is missing) along
+/// with what was expected there.
+fn parse_one_input(text: &str) -> Result<Input, (&str, ErrorKind)> {
+    let mut lines = text.lines();
+    let mut next_line = || lines.next().unwrap_or(&text[text.len()..]);
+
+    fn parse_coords(
+        line: &str,
+        prefix: &str,
+        x_prefix: &str,
+        y_prefix: &str,
+    ) -> Option<(i64, i64)> {
+        let numbers = line
+            .strip_prefix(prefix)?
+            .strip_prefix(": ")?
+            .strip_prefix(x_prefix)?;
+
+        let (x_word, tail) = numbers.split_once(", ")?;
+        let x = x_word.parse().ok()?;
+        let y = tail.strip_prefix(y_prefix)?.parse().ok()?;
+
+        Some((x, y))
+    }
+
+    fn parse_button(line: &str, prefix: &str) -> Option<Vect<i64>> {
+        let (dx, dy) = parse_coords(line, prefix, "X+", "Y+")?;
+        Some(Vect::new(dx, dy))
+    }
+
+    let line = next_line();
+    let button_a = parse_button(line, "Button A").ok_or((line, ErrorKind::InvalidButtonA))?;
+    let line = next_line();
+    let button_b = parse_button(line, "Button B").ok_or((line, ErrorKind::InvalidButtonB))?;
+
+    let line = next_line();
+    let (prize_x, prize_y) =
+        parse_coords(line, "Prize", "X=", "Y=").ok_or((line, ErrorKind::InvalidPrize))?;
+    let prize = Pos::new(prize_x, prize_y);
+
+    Ok(Input {
+        button_a,
+        button_b,
+        prize,
+    })
+}
+
+fn find_cost(inputs: impl IntoIterator<Item = Input>) -> u64 {
+    inputs
+        .into_iter()
+        .filter_map(|input| {
+            let (a, b) = solve_one(input)?;
+            Some(a * 3 + b)
+        })
+        .sum()
+}
+
+fn solve_one(input: Input) -> Option<(u64, u64)> {
+    // solve a * button_a + b * button_b = prize, taking the cheapest presses if the buttons
+    // are collinear and there are several ways to reach the prize
+
+    let Input {
+        button_a,
+        button_b,
+        prize,
+    } = input;
+    let matrix = [vec![button_a.x, button_b.x], vec![button_a.y, button_b.y]];
+    let presses =
+        solve_linear(&matrix, &[prize.x, prize.y])?.min_cost_non_negative_integer(&[3, 1])?;
+
+    Some((presses[0].try_into().ok()?, presses[1].try_into().ok()?))
+}
+
+pub fn part1(input: &[Input]) -> u64 {
+    find_cost(input.iter().copied())
+}
+
+pub fn part2(input: &[Input]) -> u64 {
+    find_cost(input.iter().map(|&input| Input {
+        prize: input.prize + Vect::new(10000000000000, 10000000000000),
+        ..input
+    }))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Input>;
+    type Error = ErrorKind;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        parse_inputs(text)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::{parse_inputs, Day13, ErrorKind};
+
+    const SMALL_INPUT: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    common::examples! {
+        Day13;
+        small: SMALL_INPUT => part1 = 480;
+        collinear: "Button A: X+4, Y+6\nButton B: X+2, Y+3\nPrize: X=10, Y=15" => part1 = 5;
+        collinear_cheap_a: "Button A: X+4, Y+4\nButton B: X+1, Y+1\nPrize: X=5, Y=5" => part1 = 4;
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let text = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n\
+                    Button A: X+26, Y+66\nButton B: X+67 Y+21\nPrize: X=12748, Y=12176";
+        assert_eq!(
+            parse_inputs(text).err().unwrap(),
+            ParseError::new(6, 1, ErrorKind::InvalidButtonB)
+        );
+    }
+}
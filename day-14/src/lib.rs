@@ -0,0 +1,174 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use common::{
+    array2::Array2,
+    math::{Pos, Vect},
+    parse::ParseError,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Robot {
+    pos: Pos,
+    velocity: Vect,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidRobot;
+
+impl Display for InvalidRobot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected `p=<x>,<y> v=<dx>,<dy>`")
+    }
+}
+
+impl FromStr for Robot {
+    type Err = InvalidRobot;
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        fn inner(line: &str) -> Option<Robot> {
+            let (px, tail) = line.strip_prefix("p=")?.split_once(",")?;
+            let (py, tail) = tail.split_once(" v=")?;
+            let (vx, tail) = tail.split_once(",")?;
+            let vy = tail;
+            Some(Robot {
+                pos: Pos {
+                    x: px.parse().ok()?,
+                    y: py.parse().ok()?,
+                },
+                velocity: Vect {
+                    x: vx.parse().ok()?,
+                    y: vy.parse().ok()?,
+                },
+            })
+        }
+        inner(line).ok_or(InvalidRobot)
+    }
+}
+
+pub fn parse_input(text: &str) -> Result<Vec<Robot>, ParseError<InvalidRobot>> {
+    text.lines()
+        .map(|line| line.parse().map_err(|err| ParseError::at(text, line, err)))
+        .collect()
+}
+
+fn simulate(robot: Robot, steps: isize, width: isize, height: isize) -> Pos {
+    (robot.pos + robot.velocity * steps).rem_euclid(width, height)
+}
+
+pub fn part1(robots: &[Robot]) -> usize {
+    let end_positions = robots.iter().map(|&robot| simulate(robot, 100, 101, 103));
+
+    let mut first_quadrant = 0;
+    let mut second_quadrant = 0;
+    let mut third_quadrant = 0;
+    let mut fourth_quadrant = 0;
+    for Pos { x, y } in end_positions {
+        if x < 50 && y < 51 {
+            first_quadrant += 1;
+        } else if x > 50 && y < 51 {
+            second_quadrant += 1;
+        } else if x < 50 && y > 51 {
+            third_quadrant += 1;
+        } else if x > 50 && y > 51 {
+            fourth_quadrant += 1;
+        }
+    }
+    first_quadrant * second_quadrant * third_quadrant * fourth_quadrant
+}
+
+pub fn part2(robots: &[Robot]) -> isize {
+    (1..10000)
+        .max_by_key(|&second| {
+            let end_positions = robots
+                .iter()
+                .map(|&robot| simulate(robot, second, 101, 103));
+            score(end_positions)
+        })
+        .unwrap()
+}
+
+/// Draws the robots after `seconds`, such as the picture found by [`part2`].
+pub fn render_tree(robots: &[Robot], seconds: isize) -> String {
+    let mut canvas = Array2::from_element(false, 101, 103);
+    for robot in robots {
+        *canvas.get_wrapping_mut(robot.pos + robot.velocity * seconds) = true;
+    }
+    canvas
+        .render(|&robot| if robot { '#' } else { '.' })
+        .to_string()
+}
+
+fn score(robots: impl IntoIterator<Item = Pos>) -> usize {
+    let weights = Array2::from_fn(101, 103, |pos| {
+        let len = pos.y / 2;
+        (pos.x - 50).abs() <= len
+    });
+    robots.into_iter().filter(|&pos| weights[pos]).count()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+    type Error = InvalidRobot;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError<Self::Error>> {
+        parse_input(text)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use common::math::{Pos, Vect};
+
+    use crate::{render_tree, simulate, Robot};
+
+    #[test]
+    fn test_simulate() {
+        let start = Robot {
+            pos: Pos::new(2, 4),
+            velocity: Vect::new(2, -3),
+        };
+        let end = simulate(start, 5, 11, 7);
+        assert_eq!(end, Pos::new(1, 3));
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let robot = Robot {
+            pos: Pos::new(100, 0),
+            velocity: Vect::new(1, -1),
+        };
+        let picture = render_tree(&[robot], 2);
+        let lines: Vec<_> = picture.lines().collect();
+        assert_eq!(lines.len(), 103);
+        assert_eq!(lines[101].find('#'), Some(1));
+        assert_eq!(picture.matches('#').count(), 1);
+    }
+
+    #[test]
+    fn robot_from_str() {
+        assert_eq!(
+            Robot::from_str("p=0,4 v=3,-3").expect("failed to parse robot"),
+            Robot {
+                pos: Pos::new(0, 4),
+                velocity: Vect::new(3, -3)
+            }
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use std::{
+    env,
+    num::NonZeroUsize,
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use common::{
+    inputs::{InputArg, InputPattern},
+    report::{self, Format, Report},
+    runner::{self, Config, InputSource, RunError},
+};
+
+const USAGE: &str = "[--day <N|all>] [--input <FILE|VARIANT>] [--answers <DIR>] [--parallel]";
+
+struct Day {
+    number: u32,
+    run: fn(&Config) -> Result<Report, RunError>,
+}
+
+const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        run: Config::run_solution::<day_01::Day01>,
+    },
+    Day {
+        number: 2,
+        run: Config::run_solution::<day_02::Day02>,
+    },
+    Day {
+        number: 3,
+        run: Config::run_solution::<day_03::Day03>,
+    },
+    Day {
+        number: 4,
+        run: Config::run_solution::<day_04::Day04>,
+    },
+    Day {
+        number: 5,
+        run: Config::run_solution::<day_05::Day05>,
+    },
+    Day {
+        number: 6,
+        run: Config::run_solution::<day_06::Day06>,
+    },
+    Day {
+        number: 7,
+        run: Config::run_solution::<day_07::Day07>,
+    },
+    Day {
+        number: 8,
+        run: Config::run_solution::<day_08::Day08>,
+    },
+    Day {
+        number: 9,
+        run: Config::run_solution::<day_09::Day09>,
+    },
+    Day {
+        number: 10,
+        run: Config::run_solution::<day_10::Day10>,
+    },
+    Day {
+        number: 11,
+        run: Config::run_solution::<day_11::Day11>,
+    },
+    Day {
+        number: 12,
+        run: Config::run_solution::<day_12::Day12>,
+    },
+    Day {
+        number: 13,
+        run: Config::run_solution::<day_13::Day13>,
+    },
+    Day {
+        number: 14,
+        run: Config::run_solution::<day_14::Day14>,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DaySelection {
+    One(u32),
+    All,
+}
+
+#[derive(Debug)]
+struct Args {
+    days: DaySelection,
+    config: Config,
+    input: Option<InputArg>,
+    answer_dir: Option<PathBuf>,
+    /// Run the days at once and print a summary when all of them are done.
+    parallel: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut days = DaySelection::All;
+    let mut config = Config::default();
+    let mut input = None;
+    let mut answer_dir = None;
+    let mut parallel = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            "--day" => {
+                days = match value()?.as_str() {
+                    "all" => DaySelection::All,
+                    day => {
+                        DaySelection::One(day.parse().map_err(|_| format!("invalid day `{day}`"))?)
+                    }
+                }
+            }
+            "--input" => input = Some(InputArg::new(value()?)),
+            "--answers" => answer_dir = Some(value()?.into()),
+            "--parallel" => parallel = true,
+            _ => {
+                if !config.parse_flag(&arg, &mut args)? {
+                    return Err(format!("unexpected argument `{arg}`"));
+                }
+            }
+        }
+    }
+
+    if matches!(input, Some(InputArg::File(_))) && days == DaySelection::All {
+        return Err("`--input <FILE>` requires a single `--day`".to_owned());
+    }
+
+    Ok(Args {
+        days,
+        config,
+        input,
+        answer_dir,
+        parallel,
+    })
+}
+
+fn main() {
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        eprintln!("usage: runner {USAGE} {}", runner::USAGE);
+        process::exit(2);
+    });
+
+    let days: Vec<&Day> = match args.days {
+        DaySelection::All => DAYS.iter().collect(),
+        DaySelection::One(number) => match DAYS.iter().find(|day| day.number == number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("error: day {number} is not registered");
+                process::exit(2);
+            }
+        },
+    };
+
+    let pattern = InputPattern::from_env();
+    let mut jobs = vec![];
+    for day in days {
+        let input = match &args.input {
+            Some(InputArg::File(file)) => file.clone(),
+            Some(InputArg::Variant(variant)) => pattern.path(day.number, Some(variant)),
+            None => pattern.path(day.number, None),
+        };
+        if !input.is_file() {
+            eprintln!("day {}: no input at {}", day.number, input.display());
+            if args.days == DaySelection::All {
+                continue;
+            }
+            process::exit(1);
+        }
+
+        let config = Config {
+            input: InputSource::File(input),
+            answers: (args.answer_dir.as_ref())
+                .map(|dir| dir.join(format!("day-{:02}.toml", day.number))),
+            day: Some(day.number),
+            quiet: args.parallel,
+            ..args.config.clone()
+        };
+        jobs.push((day, config));
+    }
+
+    if let Some(header) = args.config.format.header() {
+        println!("{header}");
+    }
+
+    let passed = if args.parallel {
+        run_parallel(&jobs, args.config.format)
+    } else {
+        run_sequential(&jobs, args.config.format)
+    };
+    if !passed {
+        process::exit(1);
+    }
+}
+
+/// Runs the days one after another, printing their results as they come. Returns whether all
+/// of them passed.
+fn run_sequential(jobs: &[(&Day, Config)], format: Format) -> bool {
+    let mut passed = true;
+    for (day, config) in jobs {
+        if format == Format::Text {
+            println!("day {}:", day.number);
+        }
+        match (day.run)(config) {
+            Ok(report) => passed &= report.passed(),
+            Err(err) => {
+                eprintln!("error: {err}");
+                passed = false;
+            }
+        }
+    }
+    passed
+}
+
+/// Runs the days on a pool of threads and prints all results once every day is done, as a
+/// table in text format. Returns whether all of them passed.
+fn run_parallel(jobs: &[(&Day, Config)], format: Format) -> bool {
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; jobs.len()]);
+    thread::scope(|scope| {
+        for _ in 0..workers.min(jobs.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some((day, config)) = jobs.get(index) else {
+                    break;
+                };
+                let result = (day.run)(config);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    let results: Vec<(u32, Result<Report, RunError>)> = jobs
+        .iter()
+        .zip(results.into_inner().unwrap())
+        .map(|((day, _), result)| (day.number, result.expect("every day was run")))
+        .collect();
+
+    if format == Format::Text {
+        print!("{}", report::summary_table(&results));
+    } else {
+        for (day, result) in &results {
+            match result {
+                Ok(report) => {
+                    for part in &report.parts {
+                        println!(
+                            "{}",
+                            format.render_part(Some(*day), &report.parse, part, true)
+                        );
+                    }
+                }
+                Err(err) => eprintln!("error: {err}"),
+            }
+        }
+    }
+
+    results
+        .iter()
+        .all(|(_, result)| result.as_ref().is_ok_and(Report::passed))
+}